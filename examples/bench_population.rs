@@ -16,9 +16,7 @@ impl GenoType for HeavyGene {
         acc
     }
 
-    fn decode(&self) -> Self::PhenoType {
-        ()
-    }
+    fn decode(&self) -> Self::PhenoType {}
 
     fn mutate(&mut self) {}
 
@@ -1,5 +1,8 @@
-use gantan::{GenoType, Inspector, PhenoType, Population, Roulette, SimulatorBuilder};
-use ordered_float::OrderedFloat;
+use gantan::termination::MaxGenerations;
+use gantan::{
+    FitnessProportionate, Float, GenoType, Inspector, Objective, PhenoType, Population,
+    SimulatorBuilder, StdoutReporter,
+};
 use rand::prelude::*;
 
 struct Pheno<'a> {
@@ -11,7 +14,7 @@ impl<'a> PhenoType for Pheno<'a> {
     type GenoType = Gene<'a>;
 
     fn encode(&self) -> Self::GenoType {
-        let mut base: Vec<usize> = (0..self.indices.len()).into_iter().collect();
+        let mut base: Vec<usize> = (0..self.indices.len()).collect();
         let mut gene = vec![0; self.indices.len()];
 
         for (i, gene_idx) in gene.iter_mut().enumerate().take(self.indices.len()) {
@@ -47,8 +50,8 @@ impl<'a> Pheno<'a> {
         let len = self.cities.len();
 
         for i in 0..len {
-            let from = self.cities[(self.indices[i]) as usize];
-            let to = self.cities[(self.indices[(i + 1) % len]) as usize];
+            let from = self.cities[self.indices[i]];
+            let to = self.cities[self.indices[(i + 1) % len]];
             total += (((from.0 - to.0).pow(2) + (from.1 - to.1).pow(2)) as f64).sqrt();
         }
 
@@ -63,19 +66,22 @@ struct Gene<'a> {
 }
 
 impl<'a> GenoType for Gene<'a> {
-    type Fitness = OrderedFloat<f64>;
+    type Fitness = Float<f64>;
     type PhenoType = Pheno<'a>;
 
+    // Shorter tours are better.
+    const OBJECTIVE: Objective = Objective::Minimize;
+
     fn fitness(&self) -> Self::Fitness {
-        OrderedFloat::from(100000.0 / self.measure_distance())
+        Float(self.measure_distance())
     }
 
     fn decode(&self) -> Self::PhenoType {
-        let mut base: Vec<usize> = (0..self.gene.len()).into_iter().collect();
+        let mut base: Vec<usize> = (0..self.gene.len()).collect();
 
         let mut indices = vec![];
         for i in 0..self.gene.len() {
-            let p = base.remove((self.gene[i]) as usize);
+            let p = base.remove(self.gene[i]);
             indices.push(p);
         }
 
@@ -86,11 +92,19 @@ impl<'a> GenoType for Gene<'a> {
     }
 
     fn mutate(&mut self) {
+        self.mutate_with_rng(&mut rand::thread_rng());
+    }
+
+    fn crossover(g1: &mut Self, g2: &mut Self) {
+        Self::crossover_with_rng(g1, g2, &mut rand::thread_rng());
+    }
+
+    fn mutate_with_rng(&mut self, rng: &mut dyn RngCore) {
         let len = self.gene.len();
 
         let (pos, value) = loop {
-            let p = rand::random::<usize>() % len;
-            let v = rand::random::<usize>() % (len - p);
+            let p = rng.gen_range(0..len);
+            let v = rng.gen_range(0..len - p);
             if p != len - 1 && self.gene[p] != v {
                 break (p, v);
             }
@@ -98,13 +112,8 @@ impl<'a> GenoType for Gene<'a> {
         self.gene[pos] = value;
     }
 
-    fn crossover(g1: &mut Self, g2: &mut Self) {
-        let mut rng = rand::thread_rng();
-        let mut pos = (0..g1.gene.len())
-            .into_iter()
-            .choose_multiple(&mut rng, 2)
-            .into_iter()
-            .collect::<Vec<usize>>();
+    fn crossover_with_rng(g1: &mut Self, g2: &mut Self, rng: &mut dyn RngCore) {
+        let mut pos = (0..g1.gene.len()).choose_multiple(rng, 2);
         pos.sort_unstable();
 
         let tmp = g1.gene.clone();
@@ -140,64 +149,21 @@ impl<'a> Gene<'a> {
     }
 }
 
-#[derive(Default)]
-struct CityRoulette<'a> {
-    inner: Vec<(Gene<'a>, f64)>,
-    sum: f64,
-}
-
-impl<'a> Roulette<Gene<'a>> for CityRoulette<'a> {
-    fn reset(&mut self, population: &[(Gene<'a>, <Gene<'a> as GenoType>::Fitness)]) {
-        self.inner.clear();
-        let mut last = 0.0;
-        for (g, f) in population {
-            let val = f.into_inner() + last;
-            self.inner.push((g.to_owned(), val));
-            last = val;
-        }
-        self.sum = last;
-    }
-
-    fn choose(&self) -> Gene<'a> {
-        let mut rng = thread_rng();
-        let r: f64 = rng.gen();
-        let fit_val = r * self.sum;
-
-        // binary search
-        let mut low = 0;
-        let mut high = self.inner.len();
-
-        while low != high {
-            let mid = (low + high) / 2;
-            if self.inner[mid].1 <= fit_val {
-                low = mid + 1;
-            } else {
-                high = mid;
-            }
-        }
-        if low == self.inner.len() {
-            low = self.inner.len() - 1;
-        }
-        self.inner[low].0.clone()
-    }
-}
-
 struct Ins;
 
 impl Inspector<Gene<'_>> for Ins {
     fn inspect(&mut self, generation: usize, _population: &Population<Gene>) -> bool {
-        if generation % 100 == 0 {
+        if generation.is_multiple_of(100) {
             if let Some(g) = _population.get_best() {
                 println!(
-                    "distance: {:.3}, fitness: {:.3}, {:?}",
+                    "distance: {:.3}, {:?}",
                     g.measure_distance(),
-                    g.fitness(),
                     g.decode().indices
                 );
             }
         }
 
-        generation != 5000
+        true
     }
 }
 
@@ -231,9 +197,9 @@ fn main() {
     ];
 
     let size = 5000;
-    let mut rng = rand::thread_rng();
+    let mut rng = StdRng::seed_from_u64(42);
     let mut p = vec![];
-    let v = (0..cities.len()).into_iter().collect::<Vec<usize>>();
+    let v = (0..cities.len()).collect::<Vec<usize>>();
     for _i in 0..size {
         let mut v1 = v.clone();
         v1.shuffle(&mut rng);
@@ -241,7 +207,7 @@ fn main() {
     }
 
     let inspector = Ins;
-    let selector = CityRoulette::default();
+    let selector = FitnessProportionate::with_seed(42);
 
     let mut builder = SimulatorBuilder::new();
     builder
@@ -249,7 +215,10 @@ fn main() {
         .with_inspector(inspector)
         .with_crossover_rate(0.9)
         .with_mutation_rate(0.05)
-        .with_selector(selector);
+        .with_selector(selector)
+        .with_seed(42)
+        .with_termination(MaxGenerations(5001))
+        .with_reporter(StdoutReporter);
     let mut simulator = builder.build();
     simulator.start();
 }
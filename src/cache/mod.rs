@@ -0,0 +1,154 @@
+use crate::GenoType;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::sync::Arc;
+
+/// Hit and miss counts of a [`FitnessCache`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CacheStats {
+    pub hits: usize,
+    pub misses: usize,
+    /// Entries dropped to stay within the capacity.
+    pub evictions: usize,
+}
+
+impl CacheStats {
+    /// Fraction of lookups answered from the cache, or `None` before the
+    /// first lookup.
+    pub fn hit_rate(&self) -> Option<f64> {
+        let lookups = self.hits + self.misses;
+        (lookups > 0).then(|| self.hits as f64 / lookups as f64)
+    }
+}
+
+/// Fitness values keyed by genome, evicting the least recently used entry
+/// once `capacity` is reached.
+///
+/// Each key is stored once and shared between the lookup table and the
+/// recency order.
+///
+/// The simulator uses one when configured with
+/// [`SimulatorBuilder::with_fitness_cache`](crate::SimulatorBuilder::with_fitness_cache).
+#[derive(Debug, Clone)]
+pub struct FitnessCache<K, F> {
+    capacity: usize,
+    entries: HashMap<Arc<K>, (F, u64)>,
+    recency: BTreeMap<u64, Arc<K>>,
+    tick: u64,
+    stats: CacheStats,
+}
+
+impl<K, F> FitnessCache<K, F>
+where
+    K: Hash + Eq,
+    F: Copy,
+{
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            tick: 0,
+            stats: CacheStats::default(),
+        }
+    }
+
+    /// Looks up `key`, counting a hit or a miss and marking the entry as
+    /// recently used.
+    pub fn get(&mut self, key: &K) -> Option<F> {
+        let Some((fitness, used)) = self.entries.get_mut(key) else {
+            self.stats.misses += 1;
+            return None;
+        };
+        self.stats.hits += 1;
+        self.tick += 1;
+        let key = self
+            .recency
+            .remove(used)
+            .expect("entries and recency agree");
+        *used = self.tick;
+        self.recency.insert(self.tick, key);
+        Some(*fitness)
+    }
+
+    pub fn insert(&mut self, key: K, fitness: F) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.tick += 1;
+        if let Some((stored, used)) = self.entries.get_mut(&key) {
+            *stored = fitness;
+            let key = self
+                .recency
+                .remove(used)
+                .expect("entries and recency agree");
+            *used = self.tick;
+            self.recency.insert(self.tick, key);
+            return;
+        }
+        if self.entries.len() >= self.capacity {
+            if let Some((_, oldest)) = self.recency.pop_first() {
+                self.entries.remove(&*oldest);
+                self.stats.evictions += 1;
+            }
+        }
+        let key = Arc::new(key);
+        self.recency.insert(self.tick, Arc::clone(&key));
+        self.entries.insert(key, (fitness, self.tick));
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+}
+
+/// Type-erased cache the simulator consults before evaluating a child.
+pub(crate) trait Memo<G: GenoType> {
+    fn get(&mut self, genome: &G) -> Option<G::Fitness>;
+    fn insert(&mut self, genome: &G, fitness: G::Fitness);
+    fn stats(&self) -> CacheStats;
+    /// Continues counting from `stats`, e.g. after a resume.
+    fn restore_stats(&mut self, stats: CacheStats);
+}
+
+/// A [`FitnessCache`] together with the function deriving its keys.
+pub(crate) struct Keyed<K, F, Fk> {
+    pub(crate) cache: FitnessCache<K, F>,
+    pub(crate) key: Fk,
+}
+
+impl<G, K, Fk> Memo<G> for Keyed<K, G::Fitness, Fk>
+where
+    G: GenoType,
+    K: Hash + Eq,
+    Fk: Fn(&G) -> K,
+{
+    fn get(&mut self, genome: &G) -> Option<G::Fitness> {
+        self.cache.get(&(self.key)(genome))
+    }
+
+    fn insert(&mut self, genome: &G, fitness: G::Fitness) {
+        self.cache.insert((self.key)(genome), fitness);
+    }
+
+    fn stats(&self) -> CacheStats {
+        self.cache.stats()
+    }
+
+    fn restore_stats(&mut self, stats: CacheStats) {
+        self.cache.stats = stats;
+    }
+}
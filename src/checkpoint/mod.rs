@@ -0,0 +1,51 @@
+use crate::{GenoType, History, ReplacementStrategy, Stat};
+
+/// Snapshot of a [`Simulator`](crate::Simulator) between two generations,
+/// taken with [`Simulator::checkpoint`](crate::Simulator::checkpoint) and
+/// continued with [`SimulatorBuilder::resume`](crate::SimulatorBuilder::resume).
+///
+/// The simulator derives every random number from its seed and the
+/// generation counter, and the built-in selectors draw from those
+/// generators, so a resumed run produces the same generations as one that
+/// was never interrupted. The inspector, selector, reporter and
+/// termination criterion are not part of the snapshot and are passed to
+/// the builder again.
+///
+/// The contents of a fitness cache are not saved either, only its hit and
+/// miss counts in [`Stat::cache`]. A resumed run starts with an empty
+/// cache, so children that would have been cache hits are evaluated again
+/// and `evaluations` can exceed that of an uninterrupted run.
+///
+/// With the `serde` feature the checkpoint can be written to disk in any
+/// serde format.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "G: serde::Serialize, G::Fitness: serde::Serialize",
+        deserialize = "G: serde::Deserialize<'de>, G::Fitness: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Checkpoint<G: GenoType> {
+    /// Current population with its fitness.
+    pub population: Vec<(G, G::Fitness)>,
+    /// Number of generations produced so far.
+    pub generation: usize,
+    pub evaluations: usize,
+    pub seed: u64,
+    pub crossover_rate: f64,
+    pub mutation_rate: f64,
+    pub elitism: usize,
+    pub offspring: Option<usize>,
+    pub replacement: ReplacementStrategy,
+    /// Size of the parent population (mu) the run started with.
+    pub population_size: usize,
+    /// Birth generation of each individual, for
+    /// [`ReplacePolicy::Oldest`](crate::ReplacePolicy::Oldest).
+    pub born: Vec<usize>,
+    /// Best individual seen so far, with its fitness.
+    pub best: Option<(G, G::Fitness)>,
+    pub stat: Stat,
+    pub history: Option<History>,
+}
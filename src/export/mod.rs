@@ -0,0 +1,118 @@
+use crate::{History, Stat};
+use std::fmt::Write as _;
+use std::io::{self, Write};
+
+/// Output format of the exporters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Format {
+    /// Comma separated values with a header line.
+    Csv,
+    /// One JSON object per line.
+    JsonLines,
+}
+
+/// Writes one line per generation of `history`, with the columns
+/// `generation`, `best`, `worst`, `mean`, `median`, `std_dev`,
+/// `elapsed_us` and `evaluations`.
+pub fn write_history<W: Write>(history: &History, format: Format, mut out: W) -> io::Result<()> {
+    if format == Format::Csv {
+        writeln!(
+            out,
+            "generation,best,worst,mean,median,std_dev,elapsed_us,evaluations"
+        )?;
+    }
+    for r in history {
+        let elapsed = r.elapsed.as_micros();
+        match format {
+            Format::Csv => writeln!(
+                out,
+                "{},{},{},{},{},{},{},{}",
+                r.generation, r.best, r.worst, r.mean, r.median, r.std_dev, elapsed, r.evaluations
+            )?,
+            Format::JsonLines => writeln!(
+                out,
+                "{{\"generation\":{},\"best\":{},\"worst\":{},\"mean\":{},\"median\":{},\"std_dev\":{},\"elapsed_us\":{},\"evaluations\":{}}}",
+                r.generation,
+                json_number(r.best),
+                json_number(r.worst),
+                json_number(r.mean),
+                json_number(r.median),
+                json_number(r.std_dev),
+                elapsed,
+                r.evaluations
+            )?,
+        }
+    }
+    out.flush()
+}
+
+/// Writes the phase timings of `stat` in long form, one line per sample,
+/// with the columns `sample`, `phase` and `micros`.
+///
+/// `sample` is the position of the timing among those of its phase, since
+/// [`Stat`] does not record generations. The simulator takes one sample of
+/// each phase per generation, except that a generation aborted by the
+/// [`FailurePolicy`](crate::FailurePolicy) records no `population` timing.
+/// After such a failure, equal sample numbers of different phases can
+/// belong to different generations.
+pub fn write_timings<W: Write>(stat: &Stat, format: Format, mut out: W) -> io::Result<()> {
+    let phases: Vec<_> = stat.phases().collect();
+    let samples = phases.iter().map(|(_, s)| s.len()).max().unwrap_or(0);
+
+    if format == Format::Csv {
+        writeln!(out, "sample,phase,micros")?;
+    }
+    for sample in 0..samples {
+        for (phase, timings) in &phases {
+            let Some(micros) = timings.get(sample) else {
+                continue;
+            };
+            match format {
+                Format::Csv => writeln!(out, "{},{},{}", sample, csv_field(phase), micros)?,
+                Format::JsonLines => writeln!(
+                    out,
+                    "{{\"sample\":{},\"phase\":{},\"micros\":{}}}",
+                    sample,
+                    json_string(phase),
+                    micros
+                )?,
+            }
+        }
+    }
+    out.flush()
+}
+
+/// JSON has no representation for NaN and infinities.
+fn json_number(value: f64) -> String {
+    if value.is_finite() {
+        value.to_string()
+    } else {
+        "null".to_string()
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut s = String::with_capacity(value.len() + 2);
+    s.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => s.push_str("\\\""),
+            '\\' => s.push_str("\\\\"),
+            c if c.is_control() => {
+                let _ = write!(s, "\\u{:04x}", c as u32);
+            }
+            c => s.push(c),
+        }
+    }
+    s.push('"');
+    s
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
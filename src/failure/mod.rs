@@ -0,0 +1,55 @@
+use std::fmt;
+
+/// Why [`GenoType::try_fitness`](crate::GenoType::try_fitness) could not
+/// evaluate a genome.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct FitnessError {
+    message: String,
+}
+
+impl FitnessError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for FitnessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for FitnessError {}
+
+/// What the simulator does with a child whose
+/// [`GenoType::try_fitness`](crate::GenoType::try_fitness) fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FailurePolicy<F> {
+    /// Stops the run: [`Simulator::start`](crate::Simulator::start) returns
+    /// with [`TerminationReason::Failed`](crate::TerminationReason::Failed)
+    /// and the error in [`RunResult::error`](crate::RunResult::error).
+    #[default]
+    Abort,
+    /// Drops the child and breeds a replacement from newly selected
+    /// parents. At most `rounds` extra rounds are bred per generation; if
+    /// children are still missing after that, the run aborts like
+    /// [`FailurePolicy::Abort`] with the error of the last failed child.
+    Resample { rounds: usize },
+    /// Keeps the child with the given fitness. Pick a value worse than any
+    /// genome can reach, e.g. `Float(f64::NEG_INFINITY)` when maximizing:
+    /// a failed child is indistinguishable from an individual of equal
+    /// fitness, so on a tie selection and replacement may prefer either.
+    Worst(F),
+    /// Evaluates the child up to `attempts` more times, then aborts the run
+    /// like [`FailurePolicy::Abort`].
+    Retry { attempts: usize },
+}
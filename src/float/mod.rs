@@ -0,0 +1,102 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// Totally ordered wrapper for `f32` and `f64` fitness values, so that
+/// floating point scores satisfy the `Ord` bound of
+/// [`GenoType::Fitness`](crate::GenoType::Fitness).
+///
+/// Numbers compare as usual, with `-0.0 == 0.0`. Every NaN equals every
+/// other NaN and is less than any number, which makes NaN the worst
+/// fitness under [`Objective::Maximize`]. When minimizing, override
+/// [`GenoType::is_undefined`] with [`Float::is_nan`] so NaN ranks last
+/// there as well. [`History`] always leaves NaN out of its statistics.
+///
+/// [`History`]: crate::History
+/// [`Objective::Maximize`]: crate::Objective::Maximize
+/// [`GenoType::is_undefined`]: crate::GenoType::is_undefined
+///
+/// `Float<f32>` and `Float<f64>` convert into `f64`, which the
+/// fitness-proportionate and rank selectors require.
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Float<T>(pub T);
+
+impl<T> Float<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+macro_rules! impl_float {
+    ($t: ty) => {
+        impl Float<$t> {
+            pub fn is_nan(&self) -> bool {
+                self.0.is_nan()
+            }
+
+            fn canonical_bits(self) -> u64 {
+                if self.0.is_nan() {
+                    u64::MAX
+                } else if self.0 == 0.0 {
+                    0
+                } else {
+                    self.0.to_bits() as u64
+                }
+            }
+        }
+
+        impl PartialEq for Float<$t> {
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other) == Ordering::Equal
+            }
+        }
+
+        impl Eq for Float<$t> {}
+
+        impl PartialOrd for Float<$t> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Float<$t> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                match (self.0.is_nan(), other.0.is_nan()) {
+                    (true, true) => Ordering::Equal,
+                    (true, false) => Ordering::Less,
+                    (false, true) => Ordering::Greater,
+                    (false, false) => self.0.partial_cmp(&other.0).expect("neither is NaN"),
+                }
+            }
+        }
+
+        impl Hash for Float<$t> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.canonical_bits().hash(state);
+            }
+        }
+
+        impl From<$t> for Float<$t> {
+            fn from(value: $t) -> Self {
+                Float(value)
+            }
+        }
+
+        impl From<Float<$t>> for f64 {
+            fn from(value: Float<$t>) -> Self {
+                value.0 as f64
+            }
+        }
+
+        impl fmt::Display for Float<$t> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+impl_float!(f32);
+impl_float!(f64);
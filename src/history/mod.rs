@@ -0,0 +1,114 @@
+use crate::Objective;
+use std::time::Duration;
+
+/// Fitness statistics of one generation, as recorded by [`History`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenerationRecord {
+    /// Index of the generation, starting at 0.
+    pub generation: usize,
+    pub best: f64,
+    pub worst: f64,
+    pub mean: f64,
+    pub median: f64,
+    /// Population standard deviation of the fitness values.
+    pub std_dev: f64,
+    /// Time taken to produce the generation.
+    pub elapsed: Duration,
+    /// Fitness evaluations performed so far, including this generation.
+    pub evaluations: usize,
+}
+
+/// Per-generation fitness statistics of a run.
+///
+/// Enabled with [`SimulatorBuilder::with_history`](crate::SimulatorBuilder::with_history)
+/// and available from [`Simulator::history`](crate::Simulator::history) and
+/// [`RunResult::history`](crate::RunResult::history).
+///
+/// A NaN fitness, e.g. from a NaN [`Float`](crate::Float), is the worst
+/// value under either objective: it shows up as `worst` and is left out of
+/// the other statistics.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct History {
+    records: Vec<GenerationRecord>,
+}
+
+impl History {
+    pub fn records(&self) -> &[GenerationRecord] {
+        &self.records
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    pub fn last(&self) -> Option<&GenerationRecord> {
+        self.records.last()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, GenerationRecord> {
+        self.records.iter()
+    }
+
+    /// Summarizes `fitness` into a new record. Empty populations are
+    /// skipped.
+    ///
+    /// NaN values, as converted from a NaN [`Float`](crate::Float), are the
+    /// worst fitness under either objective and are left out of the mean,
+    /// median and standard deviation.
+    pub(crate) fn record(
+        &mut self,
+        generation: usize,
+        objective: Objective,
+        fitness: Vec<f64>,
+        elapsed: Duration,
+        evaluations: usize,
+    ) {
+        if fitness.is_empty() {
+            return;
+        }
+        let undefined = fitness.iter().any(|f| f.is_nan());
+        let mut fitness: Vec<f64> = fitness.into_iter().filter(|f| !f.is_nan()).collect();
+        fitness.sort_by(|a, b| a.partial_cmp(b).expect("NaN was removed"));
+
+        let n = fitness.len();
+        let mean = fitness.iter().sum::<f64>() / n as f64;
+        let variance = fitness.iter().map(|f| (f - mean).powi(2)).sum::<f64>() / n as f64;
+        let median = match n {
+            0 => f64::NAN,
+            _ if n.is_multiple_of(2) => (fitness[n / 2 - 1] + fitness[n / 2]) / 2.0,
+            _ => fitness[n / 2],
+        };
+
+        let lowest = fitness.first().copied().unwrap_or(f64::NAN);
+        let highest = fitness.last().copied().unwrap_or(f64::NAN);
+        let (best, worst) = match objective {
+            Objective::Maximize => (highest, lowest),
+            Objective::Minimize => (lowest, highest),
+        };
+        self.records.push(GenerationRecord {
+            generation,
+            best,
+            worst: if undefined { f64::NAN } else { worst },
+            mean,
+            median,
+            std_dev: variance.sqrt(),
+            elapsed,
+            evaluations,
+        });
+    }
+}
+
+impl<'a> IntoIterator for &'a History {
+    type Item = &'a GenerationRecord;
+    type IntoIter = std::slice::Iter<'a, GenerationRecord>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.records.iter()
+    }
+}
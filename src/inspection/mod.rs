@@ -0,0 +1,173 @@
+use crate::termination::Stagnation;
+use crate::{GenoType, Inspector, Population};
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+
+/// `chain` and `every` for every [`Inspector`].
+///
+/// Like the inspectors they build, the combinators are generic over the
+/// genome type, which is inferred from the simulator, so stock inspectors
+/// such as [`PrintBest`] combine without a type annotation.
+pub trait InspectorExt<G: GenoType>: Inspector<G> + Sized {
+    /// Runs `self` and then `other` every generation. The run continues
+    /// only while both return `true`.
+    fn chain<O: Inspector<G>>(self, other: O) -> Chain<Self, O, G> {
+        Chain(self, other, PhantomData)
+    }
+
+    /// Runs `self` only every `n`-th generation, starting with the first.
+    fn every(self, n: usize) -> Every<Self, G> {
+        Every {
+            inner: self,
+            n,
+            genome: PhantomData,
+        }
+    }
+}
+
+impl<G: GenoType, T: Inspector<G>> InspectorExt<G> for T {}
+
+/// Runs two inspectors in turn. See [`InspectorExt::chain`].
+pub struct Chain<A, B, G>(pub A, pub B, PhantomData<fn() -> G>);
+
+impl<G, A, B> Inspector<G> for Chain<A, B, G>
+where
+    G: GenoType,
+    A: Inspector<G>,
+    B: Inspector<G>,
+{
+    fn inspect(&mut self, generation: usize, population: &Population<G>) -> bool {
+        let a = self.0.inspect(generation, population);
+        let b = self.1.inspect(generation, population);
+        a && b
+    }
+}
+
+/// Runs an inspector every `n` generations. See [`InspectorExt::every`].
+pub struct Every<I, G> {
+    inner: I,
+    n: usize,
+    genome: PhantomData<fn() -> G>,
+}
+
+impl<G, I> Inspector<G> for Every<I, G>
+where
+    G: GenoType,
+    I: Inspector<G>,
+{
+    fn inspect(&mut self, generation: usize, population: &Population<G>) -> bool {
+        if generation.is_multiple_of(self.n.max(1)) {
+            self.inner.inspect(generation, population)
+        } else {
+            true
+        }
+    }
+}
+
+// The genome type is only a marker, so unlike derived impls these do not
+// require it to be `Clone` or `Debug`.
+impl<A: Clone, B: Clone, G> Clone for Chain<A, B, G> {
+    fn clone(&self) -> Self {
+        Chain(self.0.clone(), self.1.clone(), PhantomData)
+    }
+}
+
+impl<A: Debug, B: Debug, G> Debug for Chain<A, B, G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Chain")
+            .field(&self.0)
+            .field(&self.1)
+            .finish()
+    }
+}
+
+impl<I: Clone, G> Clone for Every<I, G> {
+    fn clone(&self) -> Self {
+        Every {
+            inner: self.inner.clone(),
+            n: self.n,
+            genome: PhantomData,
+        }
+    }
+}
+
+impl<I: Debug, G> Debug for Every<I, G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Every")
+            .field("inner", &self.inner)
+            .field("n", &self.n)
+            .finish()
+    }
+}
+
+/// Prints the best fitness of every generation to stdout.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PrintBest;
+
+impl<G> Inspector<G> for PrintBest
+where
+    G: GenoType,
+    G::Fitness: Debug,
+{
+    fn inspect(&mut self, generation: usize, population: &Population<G>) -> bool {
+        if let Some((_, f)) = population.best() {
+            println!("generation {}: best fitness {:?}", generation, f);
+        }
+        true
+    }
+}
+
+/// Records the best fitness of every generation.
+#[derive(Debug, Clone)]
+pub struct RecordBest<F> {
+    history: Vec<F>,
+}
+
+impl<F> RecordBest<F> {
+    pub fn new() -> Self {
+        Self {
+            history: Vec::new(),
+        }
+    }
+
+    /// Best fitness per generation, in order.
+    pub fn history(&self) -> &[F] {
+        &self.history
+    }
+}
+
+impl<F> Default for RecordBest<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G: GenoType> Inspector<G> for RecordBest<G::Fitness> {
+    fn inspect(&mut self, _generation: usize, population: &Population<G>) -> bool {
+        if let Some((_, f)) = population.best() {
+            self.history.push(*f);
+        }
+        true
+    }
+}
+
+/// Stops the run once the best fitness of the population has not improved
+/// for `patience` generations.
+///
+/// This is [`Stagnation`] as an inspector, looking at the best individual
+/// of the current population rather than the best seen so far.
+#[derive(Debug, Clone)]
+pub struct EarlyStopping<F>(Stagnation<F>);
+
+impl<F> EarlyStopping<F> {
+    pub fn new(patience: usize) -> Self {
+        Self(Stagnation::new(patience))
+    }
+}
+
+impl<G: GenoType> Inspector<G> for EarlyStopping<G::Fitness> {
+    fn inspect(&mut self, _generation: usize, population: &Population<G>) -> bool {
+        let best = population.best().map(|(_, f)| *f);
+        !self.0.update::<G>(best)
+    }
+}
@@ -6,15 +6,34 @@
 //! - `GenoType`: describes a genome and how to mutate, crossover and evaluate it.
 //! - `PhenoType`: converts a phenotype to its gene representation.
 //! - `Inspector`: observes each generation and can stop the simulation.
+//!   Closures of the form `|generation, population| -> bool` are inspectors
+//!   too; see the `inspection` module for combinators and stock inspectors.
 //! - `Roulette`: selection strategy used when choosing parents.
+//! - `Reporter`: receives progress and timing events from the simulator.
+//! - `Termination`: built-in, composable stop conditions.
+//!
+//! Genomes that cannot always be evaluated implement
+//! `GenoType::try_fitness`; `SimulatorBuilder::with_failure_policy` decides
+//! what happens to children whose evaluation fails.
 
 use rand::prelude::*;
 use rand::rngs::StdRng;
 use rand::SeedableRng;
 
+pub mod cache;
+pub mod checkpoint;
+pub mod export;
+pub mod failure;
+pub mod float;
+pub mod history;
+pub mod inspection;
+pub mod report;
 pub mod selection;
-use std::collections::HashMap;
-use std::time::Instant;
+pub mod stat;
+pub mod termination;
+use cache::{Keyed, Memo};
+use std::hash::Hash;
+use std::time::{Duration, Instant};
 
 pub struct Simulator<G, I, R>
 where
@@ -26,14 +45,31 @@ where
     inspector: I,
     crossover_rate: f64,
     mutation_rate: f64,
+    elitism: usize,
+    offspring: Option<usize>,
+    replacement: ReplacementStrategy,
+    population_size: usize,
+    born: Vec<usize>,
     selector: R,
-    rng: StdRng,
+    #[cfg(feature = "parallel")]
+    parallel_selection: Option<SelectFn<G, R>>,
+    seed: u64,
     stat: Stat,
+    timing: bool,
+    reporter: Box<dyn Reporter<G>>,
+    termination: Option<Box<dyn Termination<G>>>,
+    generation: usize,
+    evaluations: usize,
+    best: Option<(G, G::Fitness)>,
+    history: Option<(History, HistoryValue<G>)>,
+    cache: Option<Box<dyn Memo<G>>>,
+    failure: FailurePolicy<G::Fitness>,
 }
 
 impl<G, I, R> Simulator<G, I, R>
 where
-    G: GenoType,
+    G: GenoType + MaybeSend,
+    G::Fitness: MaybeSend,
     I: Inspector<G>,
     R: Roulette<G>,
 {
@@ -44,125 +80,816 @@ where
         mutation_rate: f64,
         selector: R,
     ) -> Self {
+        let best = population.best().cloned();
+        let population_size = population.len();
         Self {
             population,
             inspector,
             crossover_rate,
             mutation_rate,
+            elitism: 0,
+            offspring: None,
+            replacement: ReplacementStrategy::default(),
+            population_size,
+            born: Vec::new(),
             selector,
-            rng: StdRng::from_entropy(),
+            #[cfg(feature = "parallel")]
+            parallel_selection: None,
+            seed: rand::random(),
             stat: Stat::default(),
+            timing: true,
+            reporter: Box::new(SilentReporter),
+            termination: None,
+            generation: 0,
+            evaluations: 0,
+            best,
+            history: None,
+            cache: None,
+            failure: FailurePolicy::default(),
         }
     }
 
-    #[cfg(not(feature = "parallel"))]
-    pub fn start(&mut self) {
-        println!("started: population = {}", self.population.len());
+    pub fn population(&self) -> &Population<G> {
+        &self.population
+    }
 
-        for i in 0.. {
-            self.population = self.step_generation();
+    pub fn inspector(&self) -> &I {
+        &self.inspector
+    }
 
-            if !self.inspector.inspect(i, &self.population) {
-                break;
-            };
+    pub fn inspector_mut(&mut self) -> &mut I {
+        &mut self.inspector
+    }
+
+    /// Number of generations produced so far.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Number of fitness evaluations performed so far.
+    pub fn evaluations(&self) -> usize {
+        self.evaluations
+    }
+
+    /// Best individual seen so far, with its fitness.
+    pub fn best(&self) -> Option<&(G, G::Fitness)> {
+        self.best.as_ref()
+    }
+
+    /// Captures the state needed to continue this run later with
+    /// [`SimulatorBuilder::resume`].
+    pub fn checkpoint(&self) -> Checkpoint<G> {
+        Checkpoint {
+            population: self.population.inner.clone(),
+            generation: self.generation,
+            evaluations: self.evaluations,
+            seed: self.seed,
+            crossover_rate: self.crossover_rate,
+            mutation_rate: self.mutation_rate,
+            elitism: self.elitism,
+            offspring: self.offspring,
+            replacement: self.replacement,
+            population_size: self.population_size,
+            born: self.born.clone(),
+            best: self.best.clone(),
+            stat: self.stat.clone(),
+            history: self.history().cloned(),
         }
+    }
 
-        self.stat.dump();
+    /// Per-phase timings recorded so far.
+    pub fn stat(&self) -> &Stat {
+        &self.stat
     }
 
-    #[cfg(feature = "parallel")]
-    pub fn start(&mut self)
-    where
-        G: Send,
-        G::Fitness: Send,
-    {
-        println!("started: population = {}", self.population.len());
+    /// Per-generation fitness statistics, if enabled with
+    /// [`SimulatorBuilder::with_history`].
+    pub fn history(&self) -> Option<&History> {
+        self.history.as_ref().map(|(history, _)| history)
+    }
 
-        for i in 0.. {
-            self.population = self.step_generation();
+    /// Runs generations until the inspector asks to stop or the termination
+    /// criterion is met.
+    pub fn start(&mut self) -> RunResult<G> {
+        let started = Instant::now();
+        self.reporter.on_start(&self.population);
 
-            if !self.inspector.inspect(i, &self.population) {
-                break;
+        let mut error = None;
+        let termination = loop {
+            // Checked before every generation, so a criterion already met by
+            // the initial population stops the run before it breeds.
+            if self.termination_met(started) {
+                break TerminationReason::Criterion;
+            }
+
+            let report = match self.try_step() {
+                Ok(report) => report,
+                Err(e) => {
+                    error = Some(e);
+                    break TerminationReason::Failed;
+                }
             };
-        }
 
-        self.stat.dump();
+            if !self.inspector.inspect(report.generation, &self.population) {
+                break TerminationReason::Inspector;
+            };
+        };
+
+        let result = RunResult {
+            best: self.best.clone(),
+            population: self.population.clone(),
+            generations: self.generation,
+            termination,
+            evaluations: self.evaluations,
+            stat: self.stat.clone(),
+            history: self.history().cloned(),
+            error,
+        };
+        self.reporter.on_finish(&result);
+        result
     }
 
-    #[cfg(not(feature = "parallel"))]
-    fn step_generation(&mut self) -> Population<G> {
-        macro_rules! rec {
-            ($tag: expr, $blk: stmt) => {{
-                let start = Instant::now();
-                let ret = { $blk };
-                let end = start.elapsed();
-                self.stat.record($tag, end.as_micros());
-                ret
-            }};
+    fn termination_met(&mut self, started: Instant) -> bool {
+        let Some(termination) = &mut self.termination else {
+            return false;
+        };
+        let state = RunState {
+            generation: self.generation,
+            evaluations: self.evaluations,
+            elapsed: started.elapsed(),
+            population: &self.population,
+            best: self.best.as_ref(),
+        };
+        termination.is_met(&state)
+    }
+
+    /// Advances the simulation by exactly one generation.
+    ///
+    /// The inspector is not consulted; callers driving the simulator this
+    /// way decide for themselves when to stop.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`FailurePolicy`] aborts the run. Use
+    /// [`Simulator::try_step`] to handle the error instead.
+    pub fn step(&mut self) -> GenerationReport<G::Fitness> {
+        self.try_step()
+            .unwrap_or_else(|e| panic!("fitness evaluation failed: {}", e))
+    }
+
+    /// Like [`Simulator::step`], but returns the error when the
+    /// [`FailurePolicy`] aborts the run.
+    ///
+    /// The failed generation is discarded: the population, the best
+    /// individual, the generation counter and the history are left as they
+    /// were, and [`Reporter::on_generation`] is not called. The work done
+    /// before the failure is kept, though. [`Simulator::evaluations`]
+    /// includes every [`GenoType::try_fitness`] call of the failed
+    /// generation, children evaluated successfully stay in the fitness
+    /// cache, and its hit and miss counts as well as the phase timings in
+    /// [`Simulator::stat`] include the failed generation.
+    pub fn try_step(&mut self) -> Result<GenerationReport<G::Fitness>, FitnessError> {
+        let start = self.timing.then(Instant::now);
+        self.population = self.step_generation()?;
+
+        let best = self.population.best();
+        if let Some((g, f)) = best {
+            if self
+                .best
+                .as_ref()
+                .is_none_or(|(_, bf)| is_better::<G>(f, bf))
+            {
+                self.best = Some((g.clone(), *f));
+            }
         }
 
-        let selection_result = rec!("selection", self.select_pairs());
-        let crossover_result = rec!("crossover", self.crossover(selection_result));
-        let mutation_result = rec!("mutation", self.mutate(crossover_result));
-        rec!("population", Population::from(mutation_result))
+        let report = GenerationReport {
+            generation: self.generation,
+            population_size: self.population.len(),
+            best_fitness: best.map(|(_, f)| *f),
+            elapsed: start.map_or(Duration::ZERO, |start| start.elapsed()),
+        };
+        if let Some((history, value)) = &mut self.history {
+            let fitness = self.population.inner.iter().map(|(_, f)| value(*f));
+            history.record(
+                self.generation,
+                G::OBJECTIVE,
+                fitness.collect(),
+                report.elapsed,
+                self.evaluations,
+            );
+        }
+        self.reporter.on_generation(&report);
+        self.generation += 1;
+        Ok(report)
     }
 
-    #[cfg(feature = "parallel")]
-    fn step_generation(&mut self) -> Population<G>
-    where
-        G: Send,
-        G::Fitness: Send,
-    {
+    /// Returns an endless iterator that calls [`Simulator::step`] on every
+    /// `next`. Combine it with `take`, `take_while` or `find` to decide when
+    /// to stop.
+    ///
+    /// Like [`Simulator::step`], the iterator panics if the
+    /// [`FailurePolicy`] aborts the run. Use [`Simulator::try_generations`]
+    /// to handle the error instead.
+    pub fn generations(&mut self) -> Generations<'_, G, I, R> {
+        Generations { simulator: self }
+    }
+
+    /// Like [`Simulator::generations`], but calls [`Simulator::try_step`].
+    /// When the [`FailurePolicy`] aborts the run the iterator yields the
+    /// error once and then ends.
+    pub fn try_generations(&mut self) -> TryGenerations<'_, G, I, R> {
+        TryGenerations {
+            simulator: self,
+            failed: false,
+        }
+    }
+
+    fn step_generation(&mut self) -> Result<Population<G>, FitnessError> {
         macro_rules! rec {
-            ($tag: expr, $blk: stmt) => {{
-                let start = Instant::now();
-                let ret = { $blk };
-                let end = start.elapsed();
-                self.stat.record($tag, end.as_micros());
-                ret
+            ($spent: expr, $blk: stmt) => {{
+                if self.timing {
+                    let start = Instant::now();
+                    let ret = { $blk };
+                    $spent += start.elapsed();
+                    ret
+                } else {
+                    $blk
+                }
             }};
         }
 
-        let selection_result = rec!("selection", self.select_pairs());
-        let crossover_result = rec!("crossover", self.crossover(selection_result));
-        let mutation_result = rec!("mutation", self.mutate(crossover_result));
-        rec!("population", Population::from(mutation_result))
+        let elites = match self.replacement {
+            ReplacementStrategy::Generational | ReplacementStrategy::Comma => {
+                self.population.top(self.elitism)
+            }
+            ReplacementStrategy::Plus | ReplacementStrategy::SteadyState { .. } => vec![],
+        };
+        let offspring = match self.replacement {
+            ReplacementStrategy::SteadyState { births, .. } => births,
+            _ => self
+                .offspring
+                .unwrap_or(self.population_size.saturating_sub(elites.len())),
+        };
+        let rounds = match self.failure {
+            FailurePolicy::Resample { rounds } => rounds,
+            _ => 0,
+        };
+
+        // Time spent in selection, crossover, mutation and evaluation,
+        // summed over the resampling rounds.
+        let mut spent = [Duration::ZERO; 4];
+        let mut children = Vec::with_capacity(offspring);
+        let mut origins = Vec::with_capacity(offspring);
+        let mut error = None;
+        for round in 0..=rounds {
+            let missing = offspring - children.len();
+            if missing == 0 {
+                break;
+            }
+            let streams = self.streams().resample(round);
+            let selection_result = rec!(spent[0], self.select_pairs(missing.div_ceil(2), streams));
+            let parents: Vec<_> = selection_result
+                .iter()
+                .flat_map(|((_, i1), (_, i2))| [*i1, *i2])
+                .collect();
+            let crossover_result =
+                rec!(spent[1], self.crossover(selection_result, missing, streams));
+            let mutation_result = rec!(spent[2], self.mutate(crossover_result, streams));
+            let evaluated = match rec!(spent[3], self.evaluate(mutation_result)) {
+                Ok(evaluated) => evaluated,
+                Err(e) => {
+                    error = Some(e);
+                    break;
+                }
+            };
+            for (child, parent) in evaluated.into_iter().zip(parents) {
+                match child {
+                    Ok(child) => {
+                        children.push(child);
+                        origins.push(parent);
+                    }
+                    Err(e) => error = Some(e),
+                }
+            }
+        }
+        // Recorded before bailing out, so an aborted generation still
+        // shows up in the timings.
+        if self.timing {
+            let phases = ["selection", "crossover", "mutation", "evaluation"];
+            for (phase, elapsed) in phases.into_iter().zip(spent) {
+                self.record_phase(phase, elapsed);
+            }
+        }
+        if children.len() < offspring {
+            return Err(error.expect("children only go missing when evaluation fails"));
+        }
+
+        let start = self.timing.then(Instant::now);
+        let next = self.replace(Population::from_evaluated(children), elites, origins);
+        if let Some(start) = start {
+            self.record_phase("population", start.elapsed());
+        }
+        Ok(next)
     }
 
-    fn select_pairs(&mut self) -> Vec<(G, G)> {
+    fn record_phase(&mut self, phase: &str, elapsed: Duration) {
+        self.stat.record(phase, elapsed.as_micros());
+        self.reporter.on_phase(phase, elapsed);
+    }
+
+    fn select_pairs(&mut self, count: usize, streams: Streams) -> Vec<(Parent<G>, Parent<G>)> {
         self.selector.reset(&self.population.inner);
-        let mut v = vec![];
+        let population = &self.population.inner;
 
-        for _ in 0..self.population.inner.len() / 2 {
-            let g1 = self.selector.choose();
-            let g2 = self.selector.choose();
-            v.push((g1, g2));
+        #[cfg(feature = "parallel")]
+        if let Some(select) = self.parallel_selection {
+            return select(&self.selector, population, count, streams);
         }
 
-        v
+        (0..count)
+            .map(|i| choose_pair(&self.selector, population, streams, i))
+            .collect()
     }
 
-    fn crossover(&mut self, mut parents: Vec<(G, G)>) -> Vec<G> {
-        for (g1, g2) in parents.iter_mut() {
-            let r: f64 = self.rng.gen();
-            if r < self.crossover_rate {
-                G::crossover(g1, g2);
+    /// Computes the fitness of `children`. Children that are still copies
+    /// of a parent take over its stored fitness, others are answered from
+    /// the fitness cache where possible. Only actual
+    /// [`GenoType::try_fitness`] calls count as evaluations.
+    ///
+    /// Children dropped by the [`FailurePolicy`] are returned as the error
+    /// their evaluation failed with.
+    fn evaluate(&mut self, children: Vec<Child<G>>) -> Result<Offspring<G>, FitnessError> {
+        let mut slots = Vec::with_capacity(children.len());
+        let mut misses = Vec::new();
+        for (child, copy_of) in children {
+            let known = match copy_of {
+                Some(i) => Some(self.population.inner[i].1),
+                None => self.cache.as_mut().and_then(|cache| cache.get(&child)),
+            };
+            match known {
+                Some(fitness) => slots.push(Some((child, fitness))),
+                None => {
+                    slots.push(None);
+                    misses.push(child);
+                }
             }
         }
 
-        parents.into_iter().flat_map(|(g1, g2)| [g1, g2]).collect()
+        let attempts = match self.failure {
+            FailurePolicy::Retry { attempts } => attempts + 1,
+            _ => 1,
+        };
+        let fresh = try_evaluate(misses, attempts);
+        self.evaluations += fresh.iter().map(|(_, _, calls)| calls).sum::<usize>();
+
+        // Cache every success first, so an abort further down still keeps
+        // the children evaluated after the failing one.
+        if let Some(cache) = &mut self.cache {
+            for (g, result, _) in &fresh {
+                if let Ok(f) = result {
+                    cache.insert(g, *f);
+                }
+            }
+            self.stat.record_cache(cache.stats());
+        }
+
+        let failure = self.failure;
+        let fresh: Result<Vec<_>, _> = fresh
+            .into_iter()
+            .map(|(g, result, _)| match (result, failure) {
+                (Ok(f), _) => Ok(Ok((g, f))),
+                (Err(e), FailurePolicy::Resample { .. }) => Ok(Err(e)),
+                (Err(_), FailurePolicy::Worst(f)) => Ok(Ok((g, f))),
+                (Err(e), FailurePolicy::Abort | FailurePolicy::Retry { .. }) => Err(e),
+            })
+            .collect();
+
+        let mut fresh = fresh?.into_iter();
+        let evaluated = slots
+            .into_iter()
+            .map(|slot| match slot {
+                Some(known) => Ok(known),
+                None => fresh.next().expect("one result per miss"),
+            })
+            .collect();
+        Ok(evaluated)
     }
 
-    fn mutate(&mut self, mut children: Vec<G>) -> Vec<G> {
-        for g in children.iter_mut() {
-            let r: f64 = self.rng.gen();
-            if r < self.mutation_rate {
-                g.mutate();
+    /// Forms the next generation from the evaluated children in `next`.
+    fn replace(
+        &mut self,
+        mut next: Population<G>,
+        elites: Vec<(G, G::Fitness)>,
+        origins: Vec<Option<usize>>,
+    ) -> Population<G> {
+        next.inner.splice(0..0, elites);
+
+        match self.replacement {
+            ReplacementStrategy::Generational => next,
+            ReplacementStrategy::Plus => {
+                next.merge(std::mem::take(&mut self.population));
+                next.truncate(self.population_size);
+                next
             }
+            ReplacementStrategy::Comma => {
+                next.truncate(self.population_size);
+                next
+            }
+            ReplacementStrategy::SteadyState { policy, .. } => {
+                let mut population = std::mem::take(&mut self.population);
+                if self.born.len() != population.len() {
+                    self.born = vec![0; population.len()];
+                }
+
+                let streams = self.streams();
+                for (i, (child, parent)) in next.inner.into_iter().zip(origins).enumerate() {
+                    let worst = || population.worst_index();
+                    let victim = match (policy, parent) {
+                        (ReplacePolicy::Worst, _) => worst(),
+                        (ReplacePolicy::Random, _) => (!population.is_empty()).then(|| {
+                            let mut rng = streams.rng(Task::Replacement, i);
+                            rng.gen_range(0..population.len())
+                        }),
+                        (ReplacePolicy::Oldest, _) => {
+                            (0..self.born.len()).min_by_key(|&j| self.born[j])
+                        }
+                        (ReplacePolicy::ParentIfBetter, Some(j)) => Some(j),
+                        (ReplacePolicy::ParentIfBetter, None) => worst(),
+                    };
+                    let Some(victim) = victim else { continue };
+                    if policy == ReplacePolicy::ParentIfBetter
+                        && !is_better::<G>(&child.1, &population.inner[victim].1)
+                    {
+                        continue;
+                    }
+                    population.inner[victim] = child;
+                    self.born[victim] = self.generation + 1;
+                }
+                population
+            }
+        }
+    }
+
+    fn crossover(
+        &mut self,
+        mut parents: Vec<(Parent<G>, Parent<G>)>,
+        count: usize,
+        streams: Streams,
+    ) -> Vec<Child<G>> {
+        let rate = self.crossover_rate;
+        let cross = move |(i, ((g1, i1), (g2, i2))): (usize, &mut (Parent<G>, Parent<G>))| {
+            let mut rng = streams.rng(Task::Crossover, i);
+            let r: f64 = rng.gen();
+            if r < rate {
+                G::crossover_with_rng(g1, g2, &mut rng);
+                (*i1, *i2) = (None, None);
+            }
+        };
+
+        #[cfg(not(feature = "parallel"))]
+        parents.iter_mut().enumerate().for_each(cross);
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            parents.par_iter_mut().enumerate().for_each(cross);
+        }
+
+        parents
+            .into_iter()
+            .flat_map(|(c1, c2)| [c1, c2])
+            .take(count)
+            .collect()
+    }
+
+    fn mutate(&mut self, mut children: Vec<Child<G>>, streams: Streams) -> Vec<Child<G>> {
+        let rate = self.mutation_rate;
+        let mutate = move |(i, (g, copy_of)): (usize, &mut Child<G>)| {
+            let mut rng = streams.rng(Task::Mutation, i);
+            let r: f64 = rng.gen();
+            if r < rate {
+                g.mutate_with_rng(&mut rng);
+                *copy_of = None;
+            }
+        };
+
+        #[cfg(not(feature = "parallel"))]
+        children.iter_mut().enumerate().for_each(mutate);
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            children.par_iter_mut().enumerate().for_each(mutate);
         }
 
         children
     }
+
+    fn streams(&self) -> Streams {
+        Streams {
+            seed: self.seed,
+            generation: self.generation,
+        }
+    }
+}
+
+/// Kinds of work that draw from their own random stream.
+#[derive(Clone, Copy)]
+enum Task {
+    Crossover,
+    Mutation,
+    Selection,
+    Replacement,
+}
+
+/// Random streams of one generation.
+///
+/// Every unit of work gets its own generator derived from the seed, the
+/// generation and its position, so results do not depend on the order in
+/// which the work is carried out or on the number of threads.
+#[derive(Clone, Copy)]
+struct Streams {
+    seed: u64,
+    generation: usize,
+}
+
+impl Streams {
+    fn rng(&self, task: Task, index: usize) -> StdRng {
+        let key = [self.generation as u64, task as u64, index as u64]
+            .into_iter()
+            .fold(self.seed, |acc, k| splitmix64(acc ^ k));
+        StdRng::seed_from_u64(key)
+    }
+
+    /// Streams for the `round`th rebreeding of children dropped under
+    /// [`FailurePolicy::Resample`]. Round zero is the generation itself.
+    fn resample(self, round: usize) -> Streams {
+        match round {
+            0 => self,
+            _ => Streams {
+                seed: splitmix64(self.seed ^ round as u64),
+                ..self
+            },
+        }
+    }
+}
+
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Converts a fitness value for the [`History`] statistics.
+type HistoryValue<G> = fn(<G as GenoType>::Fitness) -> f64;
+
+/// A parent chosen by the selector, with its position in the population
+/// when the selector can tell.
+type Parent<G> = (G, Option<usize>);
+
+/// A bred child with the position of the parent it is still identical to,
+/// i.e. neither crossover nor mutation was applied to it.
+type Child<G> = (G, Option<usize>);
+
+/// Evaluated children, with the error for those dropped by the
+/// [`FailurePolicy`].
+type Offspring<G> = Vec<Result<(G, <G as GenoType>::Fitness), FitnessError>>;
+
+/// A genome with the outcome of evaluating it and the number of
+/// [`GenoType::try_fitness`] calls that took.
+type Evaluated<G> = (G, Result<<G as GenoType>::Fitness, FitnessError>, usize);
+
+/// Evaluates `genomes`, calling [`GenoType::try_fitness`] up to `attempts`
+/// times per genome until it succeeds.
+fn try_evaluate<G>(genomes: Vec<G>, attempts: usize) -> Vec<Evaluated<G>>
+where
+    G: GenoType + MaybeSend,
+    G::Fitness: MaybeSend,
+{
+    let evaluate = move |g: G| {
+        let mut calls = 1;
+        let mut result = g.try_fitness();
+        while result.is_err() && calls < attempts {
+            calls += 1;
+            result = g.try_fitness();
+        }
+        (g, result, calls)
+    };
+
+    #[cfg(not(feature = "parallel"))]
+    return genomes.into_iter().map(evaluate).collect();
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        genomes.into_par_iter().map(evaluate).collect()
+    }
+}
+
+fn choose_pair<G, R>(
+    selector: &R,
+    population: &[(G, G::Fitness)],
+    streams: Streams,
+    index: usize,
+) -> (Parent<G>, Parent<G>)
+where
+    G: GenoType,
+    R: Roulette<G>,
+{
+    let mut rng = streams.rng(Task::Selection, index);
+    let mut choose = || match selector.choose_index(&mut rng) {
+        Some(i) => (population[i].0.clone(), Some(i)),
+        None => (selector.choose_with_rng(&mut rng), None),
+    };
+    let p1 = choose();
+    let p2 = choose();
+    (p1, p2)
+}
+
+#[cfg(feature = "parallel")]
+type SelectFn<G, R> =
+    fn(&R, &[(G, <G as GenoType>::Fitness)], usize, Streams) -> Vec<(Parent<G>, Parent<G>)>;
+
+#[cfg(feature = "parallel")]
+fn select_pairs_parallel<G, R>(
+    selector: &R,
+    population: &[(G, G::Fitness)],
+    count: usize,
+    streams: Streams,
+) -> Vec<(Parent<G>, Parent<G>)>
+where
+    G: GenoType + Send + Sync,
+    G::Fitness: Sync,
+    R: Roulette<G> + Sync,
+{
+    use rayon::prelude::*;
+    (0..count)
+        .into_par_iter()
+        .map(|i| choose_pair(selector, population, streams, i))
+        .collect()
+}
+
+/// Survivor selection: how the next generation is formed from the current
+/// population (mu individuals) and the offspring bred from it (lambda
+/// individuals).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReplacementStrategy {
+    /// The elites and the offspring become the next generation.
+    #[default]
+    Generational,
+    /// Parents and offspring compete; the best mu survive. Elitism is
+    /// implied and the configured elite count is ignored.
+    Plus,
+    /// The best mu of the elites and the offspring survive. Breed at least
+    /// mu offspring to keep the population size constant.
+    Comma,
+    /// Steady-state GA: every step breeds `births` children and inserts
+    /// them into the existing population one by one according to `policy`,
+    /// so the population is never rebuilt. The inspector therefore sees a
+    /// new generation every `births` births. Elitism and the offspring
+    /// count are ignored.
+    SteadyState {
+        births: usize,
+        policy: ReplacePolicy,
+    },
+}
+
+/// Which individual a child replaces in [`ReplacementStrategy::SteadyState`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReplacePolicy {
+    /// The least fit individual.
+    Worst,
+    /// An individual chosen uniformly at random.
+    Random,
+    /// The individual that has been in the population the longest.
+    Oldest,
+    /// The child's parent, if the child is fitter. Needs a selector that
+    /// implements [`Roulette::choose_index`]; with other selectors the
+    /// least fit individual takes the parent's place, again only if the
+    /// child is fitter.
+    ParentIfBetter,
+}
+
+/// Summary of a single generation, returned by [`Simulator::step`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenerationReport<F> {
+    /// Zero-based index of the generation, as passed to the inspector.
+    pub generation: usize,
+    pub population_size: usize,
+    pub best_fitness: Option<F>,
+    /// Wall-clock time spent producing the generation, or zero with
+    /// [`SimulatorBuilder::with_timing`] disabled.
+    pub elapsed: Duration,
+}
+
+/// Why [`Simulator::start`] stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TerminationReason {
+    /// The inspector returned `false`.
+    Inspector,
+    /// The [`Termination`] criterion was met.
+    Criterion,
+    /// A fitness evaluation failed and the [`FailurePolicy`] aborted the
+    /// run; see [`RunResult::error`].
+    Failed,
+}
+
+/// Outcome of a [`Simulator::start`] run.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "G: serde::Serialize, G::Fitness: serde::Serialize",
+        deserialize = "G: serde::Deserialize<'de>, G::Fitness: serde::Deserialize<'de>"
+    ))
+)]
+pub struct RunResult<G: GenoType> {
+    /// Best individual seen over the whole run, with its fitness.
+    pub best: Option<(G, G::Fitness)>,
+    /// Population of the last generation.
+    pub population: Population<G>,
+    /// Number of generations executed.
+    pub generations: usize,
+    pub termination: TerminationReason,
+    /// Number of fitness evaluations performed by the simulator. The
+    /// initial population is not counted, nor are children that inherited
+    /// their parent's fitness or were found in the fitness cache.
+    pub evaluations: usize,
+    /// Per-phase timings.
+    pub stat: Stat,
+    /// Per-generation fitness statistics, if enabled with
+    /// [`SimulatorBuilder::with_history`].
+    pub history: Option<History>,
+    /// The error that stopped the run, if it ended with
+    /// [`TerminationReason::Failed`].
+    pub error: Option<FitnessError>,
+}
+
+/// Iterator returned by [`Simulator::generations`].
+pub struct Generations<'a, G, I, R>
+where
+    G: GenoType,
+    I: Inspector<G>,
+    R: Roulette<G>,
+{
+    simulator: &'a mut Simulator<G, I, R>,
+}
+
+impl<G, I, R> Iterator for Generations<'_, G, I, R>
+where
+    G: GenoType + MaybeSend,
+    G::Fitness: MaybeSend,
+    I: Inspector<G>,
+    R: Roulette<G>,
+{
+    type Item = GenerationReport<G::Fitness>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.simulator.step())
+    }
+}
+
+/// Iterator returned by [`Simulator::try_generations`].
+pub struct TryGenerations<'a, G, I, R>
+where
+    G: GenoType,
+    I: Inspector<G>,
+    R: Roulette<G>,
+{
+    simulator: &'a mut Simulator<G, I, R>,
+    failed: bool,
+}
+
+impl<G, I, R> Iterator for TryGenerations<'_, G, I, R>
+where
+    G: GenoType + MaybeSend,
+    G::Fitness: MaybeSend,
+    I: Inspector<G>,
+    R: Roulette<G>,
+{
+    type Item = Result<GenerationReport<G::Fitness>, FitnessError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let step = self.simulator.try_step();
+        self.failed = step.is_err();
+        Some(step)
+    }
+}
+
+impl<G, I, R> std::iter::FusedIterator for TryGenerations<'_, G, I, R>
+where
+    G: GenoType + MaybeSend,
+    G::Fitness: MaybeSend,
+    I: Inspector<G>,
+    R: Roulette<G>,
+{
 }
 
 pub struct SimulatorBuilder<G, I, R>
@@ -175,8 +902,20 @@ where
     inspector: Option<I>,
     crossover_rate: Option<f64>,
     mutation_rate: Option<f64>,
+    elitism: usize,
+    offspring: Option<usize>,
+    replacement: ReplacementStrategy,
     selector: Option<R>,
+    #[cfg(feature = "parallel")]
+    parallel_selection: Option<SelectFn<G, R>>,
     seed: Option<u64>,
+    timing: bool,
+    reporter: Option<Box<dyn Reporter<G>>>,
+    termination: Option<Box<dyn Termination<G>>>,
+    history: Option<HistoryValue<G>>,
+    cache: Option<Box<dyn Memo<G>>>,
+    failure: FailurePolicy<G::Fitness>,
+    progress: Option<Progress<G>>,
 }
 
 impl<G, I, R> SimulatorBuilder<G, I, R>
@@ -191,8 +930,20 @@ where
             inspector: None,
             crossover_rate: None,
             mutation_rate: None,
+            elitism: 0,
+            offspring: None,
+            replacement: ReplacementStrategy::default(),
             selector: None,
+            #[cfg(feature = "parallel")]
+            parallel_selection: None,
             seed: None,
+            timing: true,
+            reporter: None,
+            termination: None,
+            history: None,
+            cache: None,
+            failure: FailurePolicy::default(),
+            progress: None,
         }
     }
 
@@ -216,34 +967,232 @@ where
         self
     }
 
+    /// Carries the `n` fittest individuals over to the next generation
+    /// unchanged. The rest of the population is bred as usual, so its size
+    /// stays constant.
+    pub fn with_elitism(&mut self, n: usize) -> &mut Self {
+        self.elitism = n;
+        self
+    }
+
+    /// Sets the number of children bred per generation (lambda),
+    /// independently of the size of the parent population (mu).
+    ///
+    /// By default as many children are bred as needed to keep the
+    /// population size constant. With an explicit count the next generation
+    /// consists of the elites followed by exactly `n` children.
+    pub fn with_offspring(&mut self, n: usize) -> &mut Self {
+        self.offspring = Some(n);
+        self
+    }
+
+    /// Sets how the next generation is formed from parents and offspring.
+    /// Defaults to [`ReplacementStrategy::Generational`].
+    pub fn with_replacement(&mut self, strategy: ReplacementStrategy) -> &mut Self {
+        self.replacement = strategy;
+        self
+    }
+
     pub fn with_selector(&mut self, selector: R) -> &mut Self {
         self.selector = Some(selector);
         self
     }
 
+    /// Draws parent pairs on the rayon thread pool instead of one after
+    /// another. Each pair is drawn with [`Roulette::choose_with_rng`], so the
+    /// selector must override it to benefit; the built-in selectors do.
+    #[cfg(feature = "parallel")]
+    pub fn with_parallel_selection(&mut self) -> &mut Self
+    where
+        G: Send + Sync,
+        G::Fitness: Sync,
+        R: Sync,
+    {
+        self.parallel_selection = Some(select_pairs_parallel::<G, R>);
+        self
+    }
+
+    /// Seeds the simulator's random number generators, which drive the
+    /// crossover and mutation coin flips and are handed to
+    /// [`Roulette::choose_with_rng`], [`GenoType::mutate_with_rng`] and
+    /// [`GenoType::crossover_with_rng`].
+    ///
+    /// Each pair and each child gets its own generator derived from the
+    /// seed, the generation and its position, so a seeded run produces the
+    /// same populations with or without the `parallel` feature.
     pub fn with_seed(&mut self, seed: u64) -> &mut Self {
         self.seed = Some(seed);
         self
     }
 
-    pub fn build(self) -> Simulator<G, I, R> {
-        let rng = match self.seed {
-            Some(s) => StdRng::seed_from_u64(s),
-            None => StdRng::from_entropy(),
-        };
+    /// Sets a stop condition checked before the first generation and after
+    /// the inspector each generation.
+    /// Combine several with [`TerminationExt::and`] and
+    /// [`TerminationExt::or`].
+    ///
+    /// To rely on the criterion alone, pass `()` as the inspector.
+    pub fn with_termination(&mut self, termination: impl Termination<G> + 'static) -> &mut Self {
+        self.termination = Some(Box::new(termination));
+        self
+    }
+
+    /// Enables or disables the per-phase timings collected in [`Stat`] and
+    /// passed to [`Reporter::on_phase`], as well as the time each generation
+    /// took. Enabled by default; when disabled no clock is read for them and
+    /// [`GenerationReport::elapsed`] and [`GenerationRecord::elapsed`] are
+    /// zero. The elapsed time of the run seen by termination criteria is
+    /// measured either way.
+    pub fn with_timing(&mut self, enabled: bool) -> &mut Self {
+        self.timing = enabled;
+        self
+    }
+
+    /// Sets the sink for progress and timing events. Defaults to
+    /// [`SilentReporter`].
+    pub fn with_reporter(&mut self, reporter: impl Reporter<G> + 'static) -> &mut Self {
+        self.reporter = Some(Box::new(reporter));
+        self
+    }
+
+    /// Records the best, worst, mean, median and standard deviation of the
+    /// fitness of every generation into a [`History`].
+    pub fn with_history(&mut self) -> &mut Self
+    where
+        G::Fitness: Into<f64>,
+    {
+        self.history = Some(Into::into);
+        self
+    }
+
+    /// Remembers the fitness of up to `capacity` genomes, evicting the least
+    /// recently used, so that children identical to an earlier individual
+    /// are not evaluated again. Hits and misses are reported in
+    /// [`Stat::cache`].
+    pub fn with_fitness_cache(&mut self, capacity: usize) -> &mut Self
+    where
+        G: Hash + Eq + 'static,
+    {
+        self.with_fitness_cache_by(capacity, G::clone)
+    }
+
+    /// Like [`with_fitness_cache`], with genomes identified by `key` instead
+    /// of by equality, e.g. only the genes that affect the fitness.
+    ///
+    /// Genomes with equal keys share one fitness value, so the key must
+    /// tell apart every pair of genomes whose fitness can differ. A hash of
+    /// the genes does not: two genomes whose hashes collide would silently
+    /// get the same fitness.
+    ///
+    /// [`with_fitness_cache`]: SimulatorBuilder::with_fitness_cache
+    pub fn with_fitness_cache_by<K>(
+        &mut self,
+        capacity: usize,
+        key: impl Fn(&G) -> K + 'static,
+    ) -> &mut Self
+    where
+        K: Hash + Eq + 'static,
+        G::Fitness: 'static,
+    {
+        self.cache = Some(Box::new(Keyed {
+            cache: FitnessCache::new(capacity),
+            key,
+        }));
+        self
+    }
+
+    /// Sets what happens to children whose [`GenoType::try_fitness`] fails.
+    /// Defaults to [`FailurePolicy::Abort`].
+    pub fn with_failure_policy(&mut self, policy: FailurePolicy<G::Fitness>) -> &mut Self {
+        self.failure = policy;
+        self
+    }
+
+    /// Continues the run captured in `checkpoint`. The population, seed,
+    /// rates and replacement settings are taken from the checkpoint and can
+    /// be overridden afterwards; the inspector and selector are still
+    /// required.
+    ///
+    /// The history is only carried over when [`with_history`] is set as well.
+    /// Likewise a fitness cache has to be configured again; it starts empty
+    /// and its hit and miss counts continue from the checkpoint.
+    ///
+    /// [`with_history`]: SimulatorBuilder::with_history
+    pub fn resume(&mut self, checkpoint: Checkpoint<G>) -> &mut Self {
+        self.population = Some(Population::from_evaluated(checkpoint.population));
+        self.crossover_rate = Some(checkpoint.crossover_rate);
+        self.mutation_rate = Some(checkpoint.mutation_rate);
+        self.elitism = checkpoint.elitism;
+        self.offspring = checkpoint.offspring;
+        self.replacement = checkpoint.replacement;
+        self.seed = Some(checkpoint.seed);
+        self.progress = Some(Progress {
+            generation: checkpoint.generation,
+            evaluations: checkpoint.evaluations,
+            population_size: checkpoint.population_size,
+            born: checkpoint.born,
+            best: checkpoint.best,
+            stat: checkpoint.stat,
+            history: checkpoint.history,
+        });
+        self
+    }
+
+    pub fn build(mut self) -> Simulator<G, I, R> {
+        let population = self.population.expect("population is required");
+        let progress = self.progress.unwrap_or_else(|| Progress {
+            generation: 0,
+            evaluations: 0,
+            population_size: population.len(),
+            born: Vec::new(),
+            best: population.best().cloned(),
+            stat: Stat::default(),
+            history: None,
+        });
+        if let (Some(cache), Some(stats)) = (&mut self.cache, progress.stat.cache()) {
+            cache.restore_stats(stats);
+        }
 
         Simulator {
-            population: self.population.expect("population is required"),
+            population,
             inspector: self.inspector.expect("inspector is required"),
             crossover_rate: self.crossover_rate.expect("crossover_rate is required"),
             mutation_rate: self.mutation_rate.expect("mutation_rate is required"),
+            elitism: self.elitism,
+            offspring: self.offspring,
+            replacement: self.replacement,
+            population_size: progress.population_size,
+            born: progress.born,
             selector: self.selector.expect("selector is required"),
-            rng,
-            stat: Stat::default(),
+            #[cfg(feature = "parallel")]
+            parallel_selection: self.parallel_selection,
+            seed: self.seed.unwrap_or_else(rand::random),
+            stat: progress.stat,
+            timing: self.timing,
+            reporter: self.reporter.unwrap_or_else(|| Box::new(SilentReporter)),
+            termination: self.termination,
+            generation: progress.generation,
+            evaluations: progress.evaluations,
+            best: progress.best,
+            history: self
+                .history
+                .map(|value| (progress.history.unwrap_or_default(), value)),
+            cache: self.cache,
+            failure: self.failure,
         }
     }
 }
 
+/// Run progress restored from a [`Checkpoint`].
+struct Progress<G: GenoType> {
+    generation: usize,
+    evaluations: usize,
+    population_size: usize,
+    born: Vec<usize>,
+    best: Option<(G, G::Fitness)>,
+    stat: Stat,
+    history: Option<History>,
+}
+
 impl<G, I, R> Default for SimulatorBuilder<G, I, R>
 where
     G: GenoType,
@@ -255,46 +1204,82 @@ where
     }
 }
 
-#[derive(Default)]
-struct Stat {
-    inner: HashMap<String, Vec<u128>>,
-}
+/// Thread-safety bound the simulator places on genomes and their fitness.
+///
+/// With the `parallel` feature enabled this requires `Send`, since
+/// individuals are evaluated on the rayon thread pool. Otherwise it is
+/// implemented for every type.
+#[cfg(feature = "parallel")]
+pub trait MaybeSend: Send {}
 
-impl Stat {
-    fn record(&mut self, tag: &str, value: u128) {
-        let v = self.inner.entry(tag.to_string()).or_default();
-        v.push(value);
-    }
+#[cfg(feature = "parallel")]
+impl<T: Send> MaybeSend for T {}
 
-    fn dump(&self) {
-        println!("[dump]");
-        for (k, v) in &self.inner {
-            let len = v.len() as u128;
-            let sum = v.iter().sum::<u128>();
-            if len > 0 {
-                println!(
-                    "{}\t: average {:6} us,\ttotal {:6} ms",
-                    k,
-                    sum / len,
-                    sum / 1000
-                );
-            }
-        }
-    }
-}
+/// Thread-safety bound the simulator places on genomes and their fitness.
+///
+/// With the `parallel` feature enabled this requires `Send`, since
+/// individuals are evaluated on the rayon thread pool. Otherwise it is
+/// implemented for every type.
+#[cfg(not(feature = "parallel"))]
+pub trait MaybeSend {}
+
+#[cfg(not(feature = "parallel"))]
+impl<T> MaybeSend for T {}
 
 pub trait Inspector<G: GenoType> {
     fn inspect(&mut self, generation: usize, population: &Population<G>) -> bool;
 }
 
+/// Inspector that never stops the run, for when a [`Termination`] criterion
+/// alone decides when to stop.
+impl<G: GenoType> Inspector<G> for () {
+    fn inspect(&mut self, _generation: usize, _population: &Population<G>) -> bool {
+        true
+    }
+}
+
+impl<G, F> Inspector<G> for F
+where
+    G: GenoType,
+    F: FnMut(usize, &Population<G>) -> bool,
+{
+    fn inspect(&mut self, generation: usize, population: &Population<G>) -> bool {
+        self(generation, population)
+    }
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        transparent,
+        bound(
+            serialize = "G: serde::Serialize, G::Fitness: serde::Serialize",
+            deserialize = "G: serde::Deserialize<'de>, G::Fitness: serde::Deserialize<'de>"
+        )
+    )
+)]
 pub struct Population<G: GenoType> {
     inner: Vec<(G, G::Fitness)>,
 }
 
+impl<G: GenoType> Default for Population<G> {
+    fn default() -> Self {
+        Self { inner: Vec::new() }
+    }
+}
+
 impl<G> Population<G>
 where
     G: GenoType,
 {
+    /// Builds a population from genomes whose fitness is already known,
+    /// without calling [`GenoType::fitness`].
+    pub fn from_evaluated(individuals: Vec<(G, G::Fitness)>) -> Self {
+        Self { inner: individuals }
+    }
+
     pub fn len(&self) -> usize {
         self.inner.len()
     }
@@ -303,8 +1288,160 @@ where
         self.inner.is_empty()
     }
 
+    /// Iterates over the individuals and their stored fitness.
+    pub fn iter(&self) -> impl Iterator<Item = (&G, &G::Fitness)> + '_ {
+        self.inner.iter().map(|(g, f)| (g, f))
+    }
+
+    /// Individual at `index`, with its fitness.
+    pub fn get(&self, index: usize) -> Option<(&G, &G::Fitness)> {
+        self.inner.get(index).map(|(g, f)| (g, f))
+    }
+
     pub fn get_best(&self) -> Option<&G> {
-        self.inner.iter().max_by_key(|val| val.1).map(|(g, _)| g)
+        self.best().map(|(g, _)| g)
+    }
+
+    pub fn get_best_with_fitness(&self) -> Option<(&G, &G::Fitness)> {
+        self.best().map(|(g, f)| (g, f))
+    }
+
+    pub fn get_worst(&self) -> Option<&G> {
+        self.get_worst_with_fitness().map(|(g, _)| g)
+    }
+
+    pub fn get_worst_with_fitness(&self) -> Option<(&G, &G::Fitness)> {
+        self.worst_index().and_then(|i| self.get(i))
+    }
+
+    /// The `n` fittest individuals, best first.
+    pub fn top_k(&self, n: usize) -> Vec<(&G, &G::Fitness)> {
+        let mut sorted = self.sorted();
+        sorted.truncate(n);
+        sorted
+    }
+
+    /// All individuals ordered best first. Individuals of equal fitness
+    /// keep their order in the population.
+    pub fn sorted(&self) -> Vec<(&G, &G::Fitness)> {
+        let mut sorted: Vec<_> = self.iter().collect();
+        sorted.sort_by(|(_, a), (_, b)| compare::<G>(b, a));
+        sorted
+    }
+
+    /// Mean fitness, or `None` for an empty population.
+    pub fn mean(&self) -> Option<f64>
+    where
+        G::Fitness: Into<f64>,
+    {
+        let n = self.inner.len() as f64;
+        (!self.is_empty()).then(|| self.inner.iter().map(|(_, f)| (*f).into()).sum::<f64>() / n)
+    }
+
+    /// Population variance of the fitness, or `None` for an empty
+    /// population.
+    pub fn variance(&self) -> Option<f64>
+    where
+        G::Fitness: Into<f64>,
+    {
+        let mean = self.mean()?;
+        let n = self.inner.len() as f64;
+        let sum = self.inner.iter().map(|(_, f)| ((*f).into() - mean).powi(2));
+        Some(sum.sum::<f64>() / n)
+    }
+
+    pub fn std_dev(&self) -> Option<f64>
+    where
+        G::Fitness: Into<f64>,
+    {
+        self.variance().map(f64::sqrt)
+    }
+
+    fn best(&self) -> Option<&(G, G::Fitness)> {
+        self.inner.iter().max_by(|a, b| compare::<G>(&a.1, &b.1))
+    }
+
+    fn worst_index(&self) -> Option<usize> {
+        (0..self.inner.len()).min_by(|&i, &j| compare::<G>(&self.inner[i].1, &self.inner[j].1))
+    }
+
+    /// Evaluates `genome` and adds it to the population.
+    pub fn push(&mut self, genome: G) {
+        let fitness = genome.fitness();
+        self.inner.push((genome, fitness));
+    }
+
+    /// Removes the individual at `index` and returns it with its fitness.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> (G, G::Fitness) {
+        self.inner.remove(index)
+    }
+
+    /// Removes every individual for which `predicate` returns `true` and
+    /// returns them in their original order.
+    pub fn remove_where<P>(&mut self, mut predicate: P) -> Vec<(G, G::Fitness)>
+    where
+        P: FnMut(&G, &G::Fitness) -> bool,
+    {
+        let (removed, kept) = std::mem::take(&mut self.inner)
+            .into_iter()
+            .partition(|(g, f)| predicate(g, f));
+        self.inner = kept;
+        removed
+    }
+
+    /// Evaluates `genome` and puts it at `index`, returning the individual
+    /// it replaces.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn replace(&mut self, index: usize, genome: G) -> (G, G::Fitness) {
+        let fitness = genome.fitness();
+        std::mem::replace(&mut self.inner[index], (genome, fitness))
+    }
+
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    /// Moves all individuals of `other` into this population.
+    pub fn merge(&mut self, mut other: Population<G>) {
+        self.inner.append(&mut other.inner);
+    }
+
+    /// Keeps only the `n` fittest individuals, ordered best first.
+    pub fn truncate(&mut self, n: usize) {
+        self.inner.sort_by(|(_, a), (_, b)| compare::<G>(b, a));
+        self.inner.truncate(n);
+    }
+
+    /// Returns copies of the `n` fittest individuals, best first.
+    fn top(&self, n: usize) -> Vec<(G, G::Fitness)> {
+        let top = self.top_k(n).into_iter();
+        top.map(|(g, f)| (g.clone(), *f)).collect()
+    }
+}
+
+/// Evaluates only the added genomes.
+impl<G: GenoType> Extend<G> for Population<G> {
+    fn extend<T: IntoIterator<Item = G>>(&mut self, genomes: T) {
+        genomes.into_iter().for_each(|g| self.push(g));
+    }
+}
+
+impl<'a, G: GenoType> IntoIterator for &'a Population<G> {
+    type Item = (&'a G, &'a G::Fitness);
+    type IntoIter = std::iter::Map<
+        std::slice::Iter<'a, (G, G::Fitness)>,
+        fn(&'a (G, G::Fitness)) -> (&'a G, &'a G::Fitness),
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter().map(|(g, f)| (g, f))
     }
 }
 
@@ -349,19 +1486,134 @@ pub trait PhenoType {
     fn encode(&self) -> Self::GenoType;
 }
 
+/// Whether larger or smaller fitness values are better.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Objective {
+    #[default]
+    Maximize,
+    Minimize,
+}
+
+impl Objective {
+    /// Orders `a` and `b` by preference: `Greater` means `a` is better.
+    pub fn compare<F: Ord>(self, a: &F, b: &F) -> std::cmp::Ordering {
+        match self {
+            Objective::Maximize => a.cmp(b),
+            Objective::Minimize => b.cmp(a),
+        }
+    }
+
+    /// Whether `a` is strictly better than `b`.
+    pub fn is_better<F: Ord>(self, a: &F, b: &F) -> bool {
+        self.compare(a, b).is_gt()
+    }
+}
+
+/// Orders fitness values of `G` by preference like [`Objective::compare`],
+/// ranking values [`GenoType::is_undefined`] deems undefined below all
+/// others.
+pub(crate) fn compare<G: GenoType>(a: &G::Fitness, b: &G::Fitness) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+    match (G::is_undefined(a), G::is_undefined(b)) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => G::OBJECTIVE.compare(a, b),
+    }
+}
+
+/// Whether `a` is strictly better than `b` under [`compare`].
+pub(crate) fn is_better<G: GenoType>(a: &G::Fitness, b: &G::Fitness) -> bool {
+    compare::<G>(a, b).is_gt()
+}
+
 pub trait GenoType: Clone {
     type Fitness: Ord + Copy;
     type PhenoType;
 
+    /// Direction of optimization, honored by the population, the simulator,
+    /// the built-in selectors, termination criteria and inspectors.
+    /// Defaults to [`Objective::Maximize`].
+    const OBJECTIVE: Objective = Objective::Maximize;
+
     fn fitness(&self) -> Self::Fitness;
+
+    /// Whether `fitness` is undefined, like a NaN [`Float`]. Undefined
+    /// values rank as the worst fitness under either [`Objective`], in the
+    /// population, the simulator, the built-in selectors, termination
+    /// criteria and inspectors. Defaults to `false`.
+    fn is_undefined(_fitness: &Self::Fitness) -> bool {
+        false
+    }
+
+    /// Evaluates the genome, reporting genomes that cannot be evaluated as
+    /// an error instead of panicking or returning a sentinel value.
+    ///
+    /// The simulator evaluates the children it breeds through this method
+    /// and handles failures according to its [`FailurePolicy`]. Populations
+    /// built with [`Population::from`] still call [`GenoType::fitness`].
+    /// Defaults to `Ok(self.fitness())`.
+    fn try_fitness(&self) -> Result<Self::Fitness, FitnessError> {
+        Ok(self.fitness())
+    }
+
     fn decode(&self) -> Self::PhenoType;
     fn mutate(&mut self);
     fn crossover(g1: &mut Self, g2: &mut Self);
+
+    /// Mutates the genome drawing randomness from `rng`.
+    ///
+    /// The simulator always calls this method with its own generator, so
+    /// overriding it makes runs reproducible under
+    /// [`SimulatorBuilder::with_seed`]. Defaults to [`GenoType::mutate`].
+    fn mutate_with_rng(&mut self, _rng: &mut dyn RngCore) {
+        self.mutate();
+    }
+
+    /// Crosses two genomes over drawing randomness from `rng`.
+    ///
+    /// See [`GenoType::mutate_with_rng`]. Defaults to
+    /// [`GenoType::crossover`].
+    fn crossover_with_rng(g1: &mut Self, g2: &mut Self, _rng: &mut dyn RngCore) {
+        Self::crossover(g1, g2);
+    }
 }
 
 pub trait Roulette<G: GenoType> {
     fn reset(&mut self, population: &[(G, G::Fitness)]);
     fn choose(&self) -> G;
+
+    /// Chooses an individual drawing randomness from `rng`.
+    ///
+    /// The simulator selects parents through this method, handing in a
+    /// generator derived from its seed. Defaults to [`Roulette::choose`].
+    fn choose_with_rng(&self, _rng: &mut dyn RngCore) -> G {
+        self.choose()
+    }
+
+    /// Chooses an individual drawing randomness from `rng` and returns its
+    /// position in the population last passed to [`Roulette::reset`].
+    ///
+    /// Selectors that can tell which individual they chose should override
+    /// this. The simulator then takes the parent straight from the
+    /// population, which lets steady-state replacement find a child's
+    /// parent. Defaults to `None`, in which case the simulator falls back to
+    /// [`Roulette::choose_with_rng`].
+    fn choose_index(&self, _rng: &mut dyn RngCore) -> Option<usize> {
+        None
+    }
 }
 
+pub use cache::{CacheStats, FitnessCache};
+pub use checkpoint::Checkpoint;
+pub use failure::{FailurePolicy, FitnessError};
+pub use float::Float;
+pub use history::{GenerationRecord, History};
+pub use inspection::InspectorExt;
+#[cfg(feature = "log")]
+pub use report::LogReporter;
+pub use report::{Reporter, SilentReporter, StdoutReporter};
 pub use selection::{FitnessProportionate, RankSelector, TournamentSelector};
+pub use stat::{PhaseTiming, Stat, TimingReport};
+pub use termination::{RunState, Termination, TerminationExt};
@@ -0,0 +1,119 @@
+use crate::{GenerationReport, GenoType, Population, RunResult};
+use std::time::Duration;
+
+/// Receives progress events from a [`Simulator`](crate::Simulator).
+///
+/// Every method has an empty default, so implementors only override the
+/// events they care about.
+pub trait Reporter<G: GenoType> {
+    /// Called once when [`Simulator::start`](crate::Simulator::start) begins.
+    fn on_start(&mut self, _population: &Population<G>) {}
+
+    /// Called after each phase of a generation with the time it took.
+    fn on_phase(&mut self, _phase: &str, _elapsed: Duration) {}
+
+    /// Called after each generation.
+    fn on_generation(&mut self, _report: &GenerationReport<G::Fitness>) {}
+
+    /// Called once when [`Simulator::start`](crate::Simulator::start) ends.
+    fn on_finish(&mut self, _result: &RunResult<G>) {}
+}
+
+/// Reporter that ignores every event. This is the default.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SilentReporter;
+
+impl<G: GenoType> Reporter<G> for SilentReporter {}
+
+/// Reporter that prints the population size at start and a per-phase timing
+/// summary at the end of a run to stdout.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StdoutReporter;
+
+impl<G: GenoType> Reporter<G> for StdoutReporter {
+    fn on_start(&mut self, population: &Population<G>) {
+        println!("started: population = {}", population.len());
+    }
+
+    fn on_finish(&mut self, result: &RunResult<G>) {
+        println!("[dump]");
+        for (phase, _) in result.stat.phases() {
+            if let (Some(average), Some(total)) =
+                (result.stat.average(phase), result.stat.total(phase))
+            {
+                println!(
+                    "{}\t: average {:6} us,\ttotal {:6} ms",
+                    phase,
+                    average,
+                    total / 1000
+                );
+            }
+        }
+        if let Some(cache) = result.stat.cache() {
+            println!("cache\t: {} hits, {} misses", cache.hits, cache.misses);
+        }
+        if let Some(error) = &result.error {
+            println!("failed\t: {}", error);
+        }
+    }
+}
+
+/// Reporter that forwards events to the [`log`] crate.
+///
+/// Run start and end are logged at `info`, generations at `debug` and phase
+/// timings at `trace`.
+#[cfg(feature = "log")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LogReporter;
+
+#[cfg(feature = "log")]
+impl<G> Reporter<G> for LogReporter
+where
+    G: GenoType,
+    G::Fitness: std::fmt::Debug,
+{
+    fn on_start(&mut self, population: &Population<G>) {
+        log::info!("started: population = {}", population.len());
+    }
+
+    fn on_phase(&mut self, phase: &str, elapsed: Duration) {
+        log::trace!("{}: {} us", phase, elapsed.as_micros());
+    }
+
+    fn on_generation(&mut self, report: &GenerationReport<G::Fitness>) {
+        log::debug!(
+            "generation {}: population = {}, best = {:?}, elapsed = {} us",
+            report.generation,
+            report.population_size,
+            report.best_fitness,
+            report.elapsed.as_micros()
+        );
+    }
+
+    fn on_finish(&mut self, result: &RunResult<G>) {
+        log::info!(
+            "finished after {} generations ({:?}), {} evaluations",
+            result.generations,
+            result.termination,
+            result.evaluations
+        );
+        for (phase, _) in result.stat.phases() {
+            if let (Some(average), Some(total)) =
+                (result.stat.average(phase), result.stat.total(phase))
+            {
+                log::info!(
+                    "{}: average {} us, total {} ms",
+                    phase,
+                    average,
+                    total / 1000
+                );
+            }
+        }
+        if let Some(cache) = result.stat.cache() {
+            log::info!("cache: {} hits, {} misses", cache.hits, cache.misses);
+        }
+        if let Some(error) = &result.error {
+            log::error!("fitness evaluation failed: {}", error);
+        }
+    }
+}
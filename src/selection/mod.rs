@@ -1,8 +1,8 @@
-use crate::{GenoType, Roulette};
+use crate::{compare, is_better, GenoType, Objective, Roulette};
 use rand::prelude::*;
 use rand::rngs::StdRng;
 use rand::SeedableRng;
-use std::cell::RefCell;
+use std::sync::Mutex;
 
 /// Fitness proportionate selection (roulette wheel)
 pub struct FitnessProportionate<G: GenoType>
@@ -11,7 +11,7 @@ where
 {
     inner: Vec<(G, f64)>,
     sum: f64,
-    rng: RefCell<StdRng>,
+    rng: Mutex<StdRng>,
 }
 
 impl<G> FitnessProportionate<G>
@@ -23,7 +23,7 @@ where
         Self {
             inner: Vec::new(),
             sum: 0.0,
-            rng: RefCell::new(StdRng::from_entropy()),
+            rng: Mutex::new(StdRng::from_entropy()),
         }
     }
 
@@ -31,7 +31,7 @@ where
         Self {
             inner: Vec::new(),
             sum: 0.0,
-            rng: RefCell::new(StdRng::seed_from_u64(seed)),
+            rng: Mutex::new(StdRng::seed_from_u64(seed)),
         }
     }
 }
@@ -53,36 +53,64 @@ where
 {
     fn reset(&mut self, population: &[(G, G::Fitness)]) {
         self.inner.clear();
+        // Undefined fitness values get no weight at all.
+        let weights: Vec<Option<f64>> = population
+            .iter()
+            .map(|(_, f)| (!G::is_undefined(f)).then(|| (*f).into()))
+            .collect();
+        // When minimizing, mirror the values so the smallest gets the largest
+        // weight: max + min - f.
+        let mirror = match G::OBJECTIVE {
+            Objective::Maximize => None,
+            Objective::Minimize => {
+                let max = weights.iter().flatten().copied().fold(f64::MIN, f64::max);
+                let min = weights.iter().flatten().copied().fold(f64::MAX, f64::min);
+                Some(max + min)
+            }
+        };
         let mut acc = 0.0;
-        for (g, f) in population {
-            acc += (*f).into();
+        for ((g, _), w) in population.iter().zip(weights) {
+            acc += w.map_or(0.0, |w| mirror.map_or(w, |m| m - w));
             self.inner.push((g.clone(), acc));
         }
         self.sum = acc;
     }
 
     fn choose(&self) -> G {
-        let mut rng = self.rng.borrow_mut();
-        let r: f64 = rng.gen::<f64>() * self.sum;
-        let mut low = 0usize;
-        let mut high = self.inner.len();
-        while low < high {
-            let mid = (low + high) / 2;
-            if self.inner[mid].1 <= r {
-                low = mid + 1;
-            } else {
-                high = mid;
-            }
+        self.choose_with_rng(&mut *self.rng.lock().unwrap())
+    }
+
+    fn choose_with_rng(&self, rng: &mut dyn RngCore) -> G {
+        self.inner[spin(&self.inner, self.sum, rng)].0.clone()
+    }
+
+    fn choose_index(&self, rng: &mut dyn RngCore) -> Option<usize> {
+        Some(spin(&self.inner, self.sum, rng))
+    }
+}
+
+/// Spins a roulette wheel over cumulative weights and returns the index of
+/// the slot the ball lands in.
+fn spin<G>(wheel: &[(G, f64)], sum: f64, rng: &mut dyn RngCore) -> usize {
+    let r: f64 = rng.gen::<f64>() * sum;
+    let mut low = 0usize;
+    let mut high = wheel.len();
+    while low < high {
+        let mid = (low + high) / 2;
+        if wheel[mid].1 <= r {
+            low = mid + 1;
+        } else {
+            high = mid;
         }
-        self.inner[low.min(self.inner.len() - 1)].0.clone()
     }
+    low.min(wheel.len() - 1)
 }
 
 /// Tournament selection
 pub struct TournamentSelector<G: GenoType> {
     size: usize,
-    population: Vec<G>,
-    rng: RefCell<StdRng>,
+    population: Vec<(G, G::Fitness)>,
+    rng: Mutex<StdRng>,
     _marker: std::marker::PhantomData<G>,
 }
 
@@ -91,7 +119,7 @@ impl<G: GenoType> TournamentSelector<G> {
         Self {
             size,
             population: Vec::new(),
-            rng: RefCell::new(StdRng::from_entropy()),
+            rng: Mutex::new(StdRng::from_entropy()),
             _marker: std::marker::PhantomData,
         }
     }
@@ -100,7 +128,7 @@ impl<G: GenoType> TournamentSelector<G> {
         Self {
             size,
             population: Vec::new(),
-            rng: RefCell::new(StdRng::seed_from_u64(seed)),
+            rng: Mutex::new(StdRng::seed_from_u64(seed)),
             _marker: std::marker::PhantomData,
         }
     }
@@ -117,22 +145,30 @@ where
     G: GenoType,
 {
     fn reset(&mut self, population: &[(G, G::Fitness)]) {
-        self.population = population.iter().map(|(g, _)| g.clone()).collect();
+        self.population = population.to_vec();
     }
 
     fn choose(&self) -> G {
-        let mut rng = self.rng.borrow_mut();
-        let mut best: Option<(G, G::Fitness)> = None;
+        self.choose_with_rng(&mut *self.rng.lock().unwrap())
+    }
+
+    fn choose_with_rng(&self, rng: &mut dyn RngCore) -> G {
+        self.population[self.choose_index(rng).unwrap()].0.clone()
+    }
+
+    /// Contestants are compared by the fitness handed to
+    /// [`Roulette::reset`], so no genome is evaluated again.
+    fn choose_index(&self, rng: &mut dyn RngCore) -> Option<usize> {
+        let mut best: Option<(usize, G::Fitness)> = None;
         for _ in 0..self.size {
             let idx = rng.gen_range(0..self.population.len());
-            let g = self.population[idx].clone();
-            let f = g.fitness();
+            let f = self.population[idx].1;
             match &best {
-                Some((_, bf)) if *bf >= f => {}
-                _ => best = Some((g, f)),
+                Some((_, bf)) if !is_better::<G>(&f, bf) => {}
+                _ => best = Some((idx, f)),
             }
         }
-        best.unwrap().0
+        best.map(|(idx, _)| idx)
     }
 }
 
@@ -142,8 +178,9 @@ where
     G::Fitness: Into<f64> + Copy,
 {
     inner: Vec<(G, f64)>,
+    indices: Vec<usize>,
     sum: f64,
-    rng: RefCell<StdRng>,
+    rng: Mutex<StdRng>,
 }
 
 impl<G> RankSelector<G>
@@ -154,16 +191,18 @@ where
     pub fn new() -> Self {
         Self {
             inner: Vec::new(),
+            indices: Vec::new(),
             sum: 0.0,
-            rng: RefCell::new(StdRng::from_entropy()),
+            rng: Mutex::new(StdRng::from_entropy()),
         }
     }
 
     pub fn with_seed(seed: u64) -> Self {
         Self {
             inner: Vec::new(),
+            indices: Vec::new(),
             sum: 0.0,
-            rng: RefCell::new(StdRng::seed_from_u64(seed)),
+            rng: Mutex::new(StdRng::seed_from_u64(seed)),
         }
     }
 }
@@ -185,31 +224,104 @@ where
 {
     fn reset(&mut self, population: &[(G, G::Fitness)]) {
         self.inner.clear();
-        let mut items: Vec<(G, G::Fitness)> =
-            population.iter().map(|(g, f)| (g.clone(), *f)).collect();
-        items.sort_by(|(_, f1), (_, f2)| f1.partial_cmp(f2).unwrap_or(std::cmp::Ordering::Equal));
+        self.indices = (0..population.len()).collect();
+        // Worst first, so the best individual gets the highest rank.
+        self.indices
+            .sort_by(|&i, &j| compare::<G>(&population[i].1, &population[j].1));
         let mut acc = 0.0;
-        for (rank, (g, _)) in items.into_iter().enumerate() {
+        for (rank, &i) in self.indices.iter().enumerate() {
             let weight = (rank + 1) as f64; // 1..n
             acc += weight;
-            self.inner.push((g, acc));
+            self.inner.push((population[i].0.clone(), acc));
         }
         self.sum = acc;
     }
 
     fn choose(&self) -> G {
-        let mut rng = self.rng.borrow_mut();
-        let r: f64 = rng.gen::<f64>() * self.sum;
-        let mut low = 0usize;
-        let mut high = self.inner.len();
-        while low < high {
-            let mid = (low + high) / 2;
-            if self.inner[mid].1 <= r {
-                low = mid + 1;
-            } else {
-                high = mid;
-            }
+        self.choose_with_rng(&mut *self.rng.lock().unwrap())
+    }
+
+    fn choose_with_rng(&self, rng: &mut dyn RngCore) -> G {
+        self.inner[spin(&self.inner, self.sum, rng)].0.clone()
+    }
+
+    fn choose_index(&self, rng: &mut dyn RngCore) -> Option<usize> {
+        Some(self.indices[spin(&self.inner, self.sum, rng)])
+    }
+}
+
+/// The built-in selectors serialize their configuration only. The
+/// population snapshot is rebuilt by the next [`Roulette::reset`], and a
+/// deserialized selector draws from a fresh generator.
+#[cfg(feature = "serde")]
+mod config {
+    use super::{FitnessProportionate, RankSelector, TournamentSelector};
+    use crate::GenoType;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "FitnessProportionate")]
+    struct FitnessProportionateConfig;
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "TournamentSelector")]
+    struct TournamentConfig {
+        size: usize,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "RankSelector")]
+    struct RankConfig;
+
+    impl<G> Serialize for FitnessProportionate<G>
+    where
+        G: GenoType,
+        G::Fitness: Into<f64> + Copy,
+    {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            FitnessProportionateConfig.serialize(serializer)
+        }
+    }
+
+    impl<'de, G> Deserialize<'de> for FitnessProportionate<G>
+    where
+        G: GenoType,
+        G::Fitness: Into<f64> + Copy,
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            FitnessProportionateConfig::deserialize(deserializer).map(|_| Self::new())
+        }
+    }
+
+    impl<G: GenoType> Serialize for TournamentSelector<G> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            TournamentConfig { size: self.size }.serialize(serializer)
+        }
+    }
+
+    impl<'de, G: GenoType> Deserialize<'de> for TournamentSelector<G> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            TournamentConfig::deserialize(deserializer).map(|c| Self::new(c.size))
+        }
+    }
+
+    impl<G> Serialize for RankSelector<G>
+    where
+        G: GenoType,
+        G::Fitness: Into<f64> + Copy,
+    {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            RankConfig.serialize(serializer)
+        }
+    }
+
+    impl<'de, G> Deserialize<'de> for RankSelector<G>
+    where
+        G: GenoType,
+        G::Fitness: Into<f64> + Copy,
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            RankConfig::deserialize(deserializer).map(|_| Self::new())
         }
-        self.inner[low.min(self.inner.len() - 1)].0.clone()
     }
 }
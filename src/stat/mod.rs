@@ -0,0 +1,126 @@
+use crate::CacheStats;
+
+/// Timings recorded for each phase of a generation, in microseconds.
+///
+/// Phases are kept in the order they were first recorded: `selection`,
+/// `crossover`, `mutation`, `evaluation` and `population`.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stat {
+    inner: Vec<(String, Vec<u128>)>,
+    cache: Option<CacheStats>,
+}
+
+impl Stat {
+    /// Iterates over the recorded phases and their per-generation samples.
+    pub fn phases(&self) -> impl Iterator<Item = (&str, &[u128])> {
+        self.inner.iter().map(|(k, v)| (k.as_str(), v.as_slice()))
+    }
+
+    /// Per-generation samples of `phase`, in microseconds.
+    pub fn samples(&self, phase: &str) -> Option<&[u128]> {
+        self.inner
+            .iter()
+            .find(|(k, _)| k == phase)
+            .map(|(_, v)| v.as_slice())
+    }
+
+    /// Average time spent in `phase` per generation, in microseconds.
+    pub fn average(&self, phase: &str) -> Option<u128> {
+        let v = self.samples(phase)?;
+        v.iter().sum::<u128>().checked_div(v.len() as u128)
+    }
+
+    /// Total time spent in `phase`, in microseconds.
+    pub fn total(&self, phase: &str) -> Option<u128> {
+        self.samples(phase).map(|v| v.iter().sum())
+    }
+
+    /// Summarizes the samples of `phase`.
+    pub fn timing(&self, phase: &str) -> Option<PhaseTiming> {
+        let samples = self.samples(phase)?;
+        PhaseTiming::new(phase, samples)
+    }
+
+    /// Summarizes every phase, in recording order.
+    pub fn report(&self) -> TimingReport {
+        TimingReport {
+            phases: self
+                .phases()
+                .filter_map(|(phase, samples)| PhaseTiming::new(phase, samples))
+                .collect(),
+        }
+    }
+
+    /// Fitness cache hits and misses so far, if the simulator has a cache.
+    pub fn cache(&self) -> Option<CacheStats> {
+        self.cache
+    }
+
+    pub(crate) fn record_cache(&mut self, stats: CacheStats) {
+        self.cache = Some(stats);
+    }
+
+    pub(crate) fn record(&mut self, tag: &str, value: u128) {
+        match self.inner.iter_mut().find(|(k, _)| k == tag) {
+            Some((_, v)) => v.push(value),
+            None => self.inner.push((tag.to_string(), vec![value])),
+        }
+    }
+}
+
+/// Summary of the samples of one phase, in microseconds. Percentiles use
+/// the nearest-rank method.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhaseTiming {
+    pub phase: String,
+    pub samples: usize,
+    pub total: u128,
+    pub min: u128,
+    pub max: u128,
+    pub mean: u128,
+    pub p50: u128,
+    pub p95: u128,
+    pub p99: u128,
+}
+
+impl PhaseTiming {
+    fn new(phase: &str, samples: &[u128]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let n = sorted.len();
+        let percentile = |p: usize| sorted[(p * n).div_ceil(100).clamp(1, n) - 1];
+        let total = sorted.iter().sum::<u128>();
+
+        Some(Self {
+            phase: phase.to_string(),
+            samples: n,
+            total,
+            min: *sorted.first()?,
+            max: *sorted.last()?,
+            mean: total / n as u128,
+            p50: percentile(50),
+            p95: percentile(95),
+            p99: percentile(99),
+        })
+    }
+}
+
+/// Per-phase timing summary returned by [`Stat::report`].
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimingReport {
+    /// One entry per phase, in recording order.
+    pub phases: Vec<PhaseTiming>,
+}
+
+impl TimingReport {
+    pub fn phase(&self, phase: &str) -> Option<&PhaseTiming> {
+        self.phases.iter().find(|t| t.phase == phase)
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, PhaseTiming> {
+        self.phases.iter()
+    }
+}
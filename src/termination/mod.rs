@@ -0,0 +1,228 @@
+use crate::{is_better, GenoType, Population};
+use std::fmt;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+/// State of a run handed to [`Termination`] criteria before the first
+/// generation and after each generation.
+pub struct RunState<'a, G: GenoType> {
+    /// Number of generations executed so far.
+    pub generation: usize,
+    /// Number of fitness evaluations performed by the simulator so far.
+    pub evaluations: usize,
+    /// Wall-clock time since [`Simulator::start`](crate::Simulator::start)
+    /// was called.
+    pub elapsed: Duration,
+    pub population: &'a Population<G>,
+    /// Best individual seen so far, with its fitness.
+    pub best: Option<&'a (G, G::Fitness)>,
+}
+
+/// Stop condition consulted by [`Simulator::start`](crate::Simulator::start)
+/// once before the first generation, so that it can stop a run that has
+/// nothing left to do, and after each generation, alongside the inspector.
+pub trait Termination<G: GenoType> {
+    /// Returns `true` once the run should stop.
+    fn is_met(&mut self, state: &RunState<'_, G>) -> bool;
+}
+
+/// `and` and `or` for every [`Termination`] criterion.
+///
+/// The genome type `G` is a parameter of the combined criterion as well,
+/// so criteria that work with any genome, such as [`MaxGenerations`], are
+/// combined without naming it; it is inferred from the builder the result
+/// is passed to.
+pub trait TerminationExt<G: GenoType>: Termination<G> + Sized {
+    /// Stops once both `self` and `other` are met.
+    fn and<T: Termination<G>>(self, other: T) -> And<Self, T, G> {
+        And(self, other, PhantomData)
+    }
+
+    /// Stops once either `self` or `other` is met.
+    fn or<T: Termination<G>>(self, other: T) -> Or<Self, T, G> {
+        Or(self, other, PhantomData)
+    }
+}
+
+impl<G: GenoType, T: Termination<G>> TerminationExt<G> for T {}
+
+/// Met when both criteria are met. See [`TerminationExt::and`].
+///
+/// Both criteria see every generation, so stateful ones such as
+/// [`Stagnation`] keep counting.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct And<A, B, G>(
+    pub A,
+    pub B,
+    #[cfg_attr(feature = "serde", serde(skip))] PhantomData<fn() -> G>,
+);
+
+impl<G, A, B> Termination<G> for And<A, B, G>
+where
+    G: GenoType,
+    A: Termination<G>,
+    B: Termination<G>,
+{
+    fn is_met(&mut self, state: &RunState<'_, G>) -> bool {
+        let a = self.0.is_met(state);
+        let b = self.1.is_met(state);
+        a && b
+    }
+}
+
+/// Met when either criterion is met. See [`TerminationExt::or`].
+///
+/// Both criteria see every generation, so stateful ones such as
+/// [`Stagnation`] keep counting.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Or<A, B, G>(
+    pub A,
+    pub B,
+    #[cfg_attr(feature = "serde", serde(skip))] PhantomData<fn() -> G>,
+);
+
+impl<G, A, B> Termination<G> for Or<A, B, G>
+where
+    G: GenoType,
+    A: Termination<G>,
+    B: Termination<G>,
+{
+    fn is_met(&mut self, state: &RunState<'_, G>) -> bool {
+        let a = self.0.is_met(state);
+        let b = self.1.is_met(state);
+        a || b
+    }
+}
+
+// Implemented by hand: a derive would require `G: Clone + Debug`.
+macro_rules! impl_pair {
+    ($name: ident) => {
+        impl<A: Clone, B: Clone, G> Clone for $name<A, B, G> {
+            fn clone(&self) -> Self {
+                $name(self.0.clone(), self.1.clone(), PhantomData)
+            }
+        }
+
+        impl<A: fmt::Debug, B: fmt::Debug, G> fmt::Debug for $name<A, B, G> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($name))
+                    .field(&self.0)
+                    .field(&self.1)
+                    .finish()
+            }
+        }
+    };
+}
+
+impl_pair!(And);
+impl_pair!(Or);
+
+/// Met after the given number of generations.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaxGenerations(pub usize);
+
+impl<G: GenoType> Termination<G> for MaxGenerations {
+    fn is_met(&mut self, state: &RunState<'_, G>) -> bool {
+        state.generation >= self.0
+    }
+}
+
+/// Met once the simulator has performed the given number of fitness
+/// evaluations.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaxEvaluations(pub usize);
+
+impl<G: GenoType> Termination<G> for MaxEvaluations {
+    fn is_met(&mut self, state: &RunState<'_, G>) -> bool {
+        state.evaluations >= self.0
+    }
+}
+
+/// Met once the run has taken the given wall-clock time.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeLimit(pub Duration);
+
+impl<G: GenoType> Termination<G> for TimeLimit {
+    fn is_met(&mut self, state: &RunState<'_, G>) -> bool {
+        state.elapsed >= self.0
+    }
+}
+
+/// Met once an individual is at least as good as the given fitness under
+/// [`GenoType::OBJECTIVE`].
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TargetFitness<F>(pub F);
+
+impl<G: GenoType> Termination<G> for TargetFitness<G::Fitness> {
+    fn is_met(&mut self, state: &RunState<'_, G>) -> bool {
+        state.best.is_some_and(|(_, f)| !is_better::<G>(&self.0, f))
+    }
+}
+
+/// Met when the best fitness has not improved for the given number of
+/// generations.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stagnation<F> {
+    generations: usize,
+    best: Option<F>,
+    since: usize,
+}
+
+impl<F> Stagnation<F> {
+    pub fn new(generations: usize) -> Self {
+        Self {
+            generations,
+            best: None,
+            since: 0,
+        }
+    }
+}
+
+impl<F: Copy> Stagnation<F> {
+    /// Takes the best fitness of the latest generation and returns whether
+    /// it has not improved for the configured number of generations.
+    pub(crate) fn update<G: GenoType<Fitness = F>>(&mut self, best: Option<F>) -> bool {
+        let improved = best.is_some_and(|b| {
+            self.best
+                .is_none_or(|previous| is_better::<G>(&b, &previous))
+        });
+        if improved {
+            self.best = best;
+            self.since = 0;
+        } else {
+            self.since += 1;
+        }
+        self.since >= self.generations
+    }
+}
+
+impl<G: GenoType> Termination<G> for Stagnation<G::Fitness> {
+    fn is_met(&mut self, state: &RunState<'_, G>) -> bool {
+        self.update::<G>(state.best.map(|(_, f)| *f))
+    }
+}
+
+/// Met when the standard deviation of fitness across the population drops
+/// below the given threshold.
+///
+/// This measures the spread of fitness values, not of genomes: different
+/// genomes with equal fitness count as no spread at all, so on a fitness
+/// plateau the criterion is met even if the population is still diverse.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LowFitnessSpread(pub f64);
+
+impl<G> Termination<G> for LowFitnessSpread
+where
+    G: GenoType,
+    G::Fitness: Into<f64>,
+{
+    fn is_met(&mut self, state: &RunState<'_, G>) -> bool {
+        state.population.std_dev().is_some_and(|sd| sd < self.0)
+    }
+}
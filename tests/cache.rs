@@ -0,0 +1,158 @@
+use gantan::{
+    CacheStats, FitnessCache, GenoType, Inspector, Population, Roulette, SimulatorBuilder,
+};
+use std::cell::Cell;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct G(u32);
+
+impl GenoType for G {
+    type Fitness = u32;
+    type PhenoType = u32;
+
+    fn fitness(&self) -> Self::Fitness {
+        self.0
+    }
+    fn decode(&self) -> Self::PhenoType {
+        self.0
+    }
+    fn mutate(&mut self) {}
+    fn crossover(_g1: &mut Self, _g2: &mut Self) {}
+}
+
+/// Picks every individual in turn, so each generation breeds clones of the
+/// previous one.
+#[derive(Default)]
+struct Cycle(Vec<G>, Cell<usize>);
+
+impl Roulette<G> for Cycle {
+    fn reset(&mut self, population: &[(G, u32)]) {
+        self.0 = population.iter().map(|(g, _)| g.clone()).collect();
+        self.1.set(0);
+    }
+    fn choose(&self) -> G {
+        let i = self.1.get();
+        self.1.set(i + 1);
+        self.0[i % self.0.len()].clone()
+    }
+}
+
+struct ThreeGenerations;
+
+impl Inspector<G> for ThreeGenerations {
+    fn inspect(&mut self, generation: usize, _p: &Population<G>) -> bool {
+        generation < 2
+    }
+}
+
+fn builder() -> SimulatorBuilder<G, ThreeGenerations, Cycle> {
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(vec![G(1), G(2), G(3), G(4)]))
+        .with_inspector(ThreeGenerations)
+        .with_crossover_rate(0.0)
+        .with_mutation_rate(0.0)
+        .with_selector(Cycle::default());
+    builder
+}
+
+#[test]
+fn evicts_least_recently_used() {
+    let mut cache = FitnessCache::new(2);
+    cache.insert("a", 1);
+    cache.insert("b", 2);
+    assert_eq!(cache.get(&"a"), Some(1));
+    cache.insert("c", 3);
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.get(&"b"), None);
+    assert_eq!(cache.get(&"a"), Some(1));
+    assert_eq!(cache.get(&"c"), Some(3));
+    assert_eq!(
+        cache.stats(),
+        CacheStats {
+            hits: 3,
+            misses: 1,
+            evictions: 1
+        }
+    );
+    assert_eq!(cache.stats().hit_rate(), Some(0.75));
+
+    let mut disabled = FitnessCache::new(0);
+    disabled.insert("a", 1);
+    assert!(disabled.is_empty());
+}
+
+/// Not `Clone`: the cache keeps the one copy it is given.
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct Key(u32);
+
+#[test]
+fn reinserting_updates_without_evicting() {
+    let mut cache = FitnessCache::new(2);
+    cache.insert(Key(1), 1);
+    cache.insert(Key(2), 2);
+    cache.insert(Key(1), 10);
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.stats().evictions, 0);
+
+    // Key(2) is now the least recently used.
+    cache.insert(Key(3), 3);
+    assert_eq!(cache.get(&Key(2)), None);
+    assert_eq!(cache.get(&Key(1)), Some(10));
+    assert_eq!(cache.get(&Key(3)), Some(3));
+}
+
+#[test]
+fn simulator_skips_cached_genomes() {
+    let mut builder = builder();
+    builder.with_fitness_cache(16);
+    let mut simulator = builder.build();
+
+    let result = simulator.start();
+    // Only the first generation's children are evaluated.
+    assert_eq!(result.generations, 3);
+    assert_eq!(result.evaluations, 4);
+    let stats = result.stat.cache().unwrap();
+    assert_eq!((stats.hits, stats.misses), (8, 4));
+    assert_eq!(simulator.stat().cache(), Some(stats));
+}
+
+#[test]
+fn resumed_cache_keeps_counting() {
+    let mut first = builder();
+    first.with_fitness_cache(16);
+    let mut simulator = first.build();
+    simulator.step();
+    simulator.step();
+    let saved = simulator.stat().cache().unwrap();
+    assert_eq!((saved.hits, saved.misses), (4, 4));
+
+    let mut resumed = builder();
+    resumed
+        .with_fitness_cache(16)
+        .resume(simulator.checkpoint());
+    let mut resumed = resumed.build();
+    assert_eq!(resumed.stat().cache(), Some(saved));
+    resumed.step();
+    // The cache starts empty, so the copies are evaluated again.
+    let stats = resumed.stat().cache().unwrap();
+    assert_eq!((stats.hits, stats.misses), (4, 8));
+    assert_eq!(resumed.checkpoint().evaluations, 8);
+}
+
+#[test]
+fn genomes_are_identified_by_key() {
+    let mut builder = builder();
+    builder.with_fitness_cache_by(16, |_: &G| ());
+    let result = builder.build().start();
+    // Every genome shares one key, so the last fitness stored is reused.
+    let fitness: Vec<u32> = result.population.iter().map(|(_, f)| *f).collect();
+    assert_eq!(fitness, [4, 4, 4, 4]);
+}
+
+#[test]
+fn no_cache_by_default() {
+    let result = builder().build().start();
+    assert_eq!(result.evaluations, 12);
+    assert_eq!(result.stat.cache(), None);
+}
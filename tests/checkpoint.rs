@@ -0,0 +1,126 @@
+use gantan::{
+    Checkpoint, GenoType, Population, ReplacePolicy, ReplacementStrategy, SimulatorBuilder,
+    TournamentSelector,
+};
+use rand::prelude::*;
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Bits(u32);
+
+impl GenoType for Bits {
+    type Fitness = u32;
+    type PhenoType = u32;
+
+    fn fitness(&self) -> Self::Fitness {
+        self.0.count_ones()
+    }
+    fn decode(&self) -> Self::PhenoType {
+        self.0
+    }
+    fn mutate(&mut self) {}
+    fn crossover(_g1: &mut Self, _g2: &mut Self) {}
+    fn mutate_with_rng(&mut self, rng: &mut dyn RngCore) {
+        self.0 ^= 1 << rng.gen_range(0..32);
+    }
+    fn crossover_with_rng(g1: &mut Self, g2: &mut Self, rng: &mut dyn RngCore) {
+        let mask: u32 = rng.gen();
+        let (a, b) = (g1.0, g2.0);
+        g1.0 = (a & mask) | (b & !mask);
+        g2.0 = (b & mask) | (a & !mask);
+    }
+}
+
+fn builder(
+    replacement: ReplacementStrategy,
+) -> SimulatorBuilder<Bits, (), TournamentSelector<Bits>> {
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_inspector(())
+        .with_selector(TournamentSelector::new(3))
+        .with_history();
+    builder
+        .with_population(Population::from((0..8).map(Bits).collect::<Vec<_>>()))
+        .with_crossover_rate(0.7)
+        .with_mutation_rate(0.3)
+        .with_elitism(1)
+        .with_replacement(replacement)
+        .with_seed(11);
+    builder
+}
+
+fn resumed(checkpoint: Checkpoint<Bits>) -> SimulatorBuilder<Bits, (), TournamentSelector<Bits>> {
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_inspector(())
+        .with_selector(TournamentSelector::new(3))
+        .with_history()
+        .resume(checkpoint);
+    builder
+}
+
+fn genomes(checkpoint: &Checkpoint<Bits>) -> Vec<(u32, u32)> {
+    checkpoint
+        .population
+        .iter()
+        .map(|(g, f)| (g.0, *f))
+        .collect()
+}
+
+fn assert_resumes(replacement: ReplacementStrategy) {
+    let mut straight = builder(replacement).build();
+    for _ in 0..10 {
+        straight.step();
+    }
+    let expected = straight.checkpoint();
+
+    let mut first = builder(replacement).build();
+    for _ in 0..4 {
+        first.step();
+    }
+    let mut second = resumed(first.checkpoint()).build();
+    for _ in 0..6 {
+        second.step();
+    }
+    let actual = second.checkpoint();
+
+    assert_eq!(actual.generation, 10);
+    assert_eq!(actual.evaluations, expected.evaluations);
+    assert_eq!(actual.best, expected.best);
+    assert_eq!(actual.born, expected.born);
+    assert_eq!(genomes(&actual), genomes(&expected));
+    assert_eq!(actual.history.unwrap().len(), 10);
+    assert_eq!(actual.stat.samples("mutation").unwrap().len(), 10);
+}
+
+#[test]
+fn resumes_generational_run() {
+    assert_resumes(ReplacementStrategy::Generational);
+}
+
+#[test]
+fn resumes_steady_state_run() {
+    assert_resumes(ReplacementStrategy::SteadyState {
+        births: 2,
+        policy: ReplacePolicy::Oldest,
+    });
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn checkpoint_round_trips_through_serde() {
+    let mut simulator = builder(ReplacementStrategy::Generational).build();
+    simulator.step();
+    simulator.step();
+    let json = serde_json::to_string(&simulator.checkpoint()).unwrap();
+    let checkpoint: Checkpoint<Bits> = serde_json::from_str(&json).unwrap();
+
+    let mut restored = resumed(checkpoint).build();
+    assert_eq!(restored.generation(), 2);
+    restored.step();
+    simulator.step();
+    assert_eq!(
+        genomes(&restored.checkpoint()),
+        genomes(&simulator.checkpoint())
+    );
+}
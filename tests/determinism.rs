@@ -0,0 +1,85 @@
+use gantan::{GenoType, Inspector, Population, SimulatorBuilder, TournamentSelector};
+use rand::prelude::*;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Bits(u64);
+
+impl GenoType for Bits {
+    type Fitness = u32;
+    type PhenoType = u64;
+
+    fn fitness(&self) -> Self::Fitness {
+        self.0.count_ones()
+    }
+    fn decode(&self) -> Self::PhenoType {
+        self.0
+    }
+    fn mutate(&mut self) {
+        self.mutate_with_rng(&mut thread_rng());
+    }
+    fn crossover(g1: &mut Self, g2: &mut Self) {
+        Self::crossover_with_rng(g1, g2, &mut thread_rng());
+    }
+    fn mutate_with_rng(&mut self, rng: &mut dyn RngCore) {
+        self.0 ^= 1 << rng.gen_range(0..64);
+    }
+    fn crossover_with_rng(g1: &mut Self, g2: &mut Self, rng: &mut dyn RngCore) {
+        let mask = u64::MAX << rng.gen_range(0..64);
+        let (a, b) = (g1.0, g2.0);
+        g1.0 = (a & mask) | (b & !mask);
+        g2.0 = (b & mask) | (a & !mask);
+    }
+}
+
+struct Generations(usize);
+
+impl Inspector<Bits> for Generations {
+    fn inspect(&mut self, generation: usize, _p: &Population<Bits>) -> bool {
+        generation + 1 < self.0
+    }
+}
+
+/// The final population and the best individual of a seeded run.
+type Outcome = (Vec<(u64, u32)>, Option<(u64, u32)>);
+
+fn run(seed: u64, parallel_selection: bool) -> Outcome {
+    let genes = (0..8).map(|i| Bits(i * 0x0101_0101)).collect::<Vec<_>>();
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(genes))
+        .with_inspector(Generations(11))
+        .with_crossover_rate(0.7)
+        .with_mutation_rate(0.3)
+        .with_selector(TournamentSelector::with_seed(2, seed))
+        .with_seed(seed);
+    if parallel_selection {
+        #[cfg(feature = "parallel")]
+        builder.with_parallel_selection();
+    }
+    let result = builder.build().start();
+    let population = result.population.iter().map(|(g, f)| (g.0, *f)).collect();
+    (population, result.best.map(|(g, f)| (g.0, f)))
+}
+
+#[test]
+fn seeded_run_is_reproducible() {
+    let expected = run(2024, false);
+    assert_eq!(run(2024, false), expected);
+    assert_ne!(run(2025, false), expected);
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn seeded_run_ignores_thread_count() {
+    let on = |threads, parallel_selection| {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap()
+            .install(|| run(2024, parallel_selection))
+    };
+    let expected = on(1, false);
+    assert_eq!(on(4, false), expected);
+    assert_eq!(on(1, true), expected);
+    assert_eq!(on(4, true), expected);
+}
@@ -0,0 +1,112 @@
+use gantan::export::{write_history, write_timings, Format};
+use gantan::termination::MaxGenerations;
+use gantan::{GenoType, Population, Roulette, RunResult, SimulatorBuilder};
+
+#[derive(Clone, Debug, PartialEq)]
+struct G(u32);
+
+impl GenoType for G {
+    type Fitness = u32;
+    type PhenoType = u32;
+
+    fn fitness(&self) -> Self::Fitness {
+        self.0
+    }
+    fn decode(&self) -> Self::PhenoType {
+        self.0
+    }
+    fn mutate(&mut self) {
+        self.0 += 1;
+    }
+    fn crossover(_g1: &mut Self, _g2: &mut Self) {}
+}
+
+struct First(Option<G>);
+
+impl Roulette<G> for First {
+    fn reset(&mut self, population: &[(G, u32)]) {
+        self.0 = population.first().map(|(g, _)| g.clone());
+    }
+    fn choose(&self) -> G {
+        self.0.clone().unwrap()
+    }
+}
+
+fn run() -> RunResult<G> {
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(vec![G(1), G(1)]))
+        .with_inspector(())
+        .with_crossover_rate(0.0)
+        .with_mutation_rate(1.0)
+        .with_selector(First(None))
+        .with_termination(MaxGenerations(2))
+        .with_history();
+    builder.build().start()
+}
+
+fn lines(out: Vec<u8>) -> Vec<String> {
+    String::from_utf8(out)
+        .unwrap()
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+fn history_as_csv_and_json_lines() {
+    let result = run();
+    let history = result.history.unwrap();
+
+    let mut csv = Vec::new();
+    write_history(&history, Format::Csv, &mut csv).unwrap();
+    let csv = lines(csv);
+    assert_eq!(
+        csv[0],
+        "generation,best,worst,mean,median,std_dev,elapsed_us,evaluations"
+    );
+    assert_eq!(csv.len(), 3);
+    assert!(csv[2].starts_with("1,3,3,3,3,0,"));
+    assert!(csv[2].ends_with(",4"));
+
+    let mut json = Vec::new();
+    write_history(&history, Format::JsonLines, &mut json).unwrap();
+    let json = lines(json);
+    assert_eq!(json.len(), 2);
+    assert!(json[0].starts_with("{\"generation\":0,\"best\":2,\"worst\":2,"));
+    assert!(json[0].ends_with(",\"evaluations\":2}"));
+}
+
+#[test]
+fn timings_in_long_form() {
+    let result = run();
+
+    let mut csv = Vec::new();
+    write_timings(&result.stat, Format::Csv, &mut csv).unwrap();
+    let csv = lines(csv);
+    assert_eq!(csv[0], "sample,phase,micros");
+    let rows: Vec<(&str, &str)> = csv[1..]
+        .iter()
+        .map(|l| {
+            let mut cols = l.split(',');
+            (cols.next().unwrap(), cols.next().unwrap())
+        })
+        .collect();
+    for sample in ["0", "1"] {
+        for phase in [
+            "selection",
+            "crossover",
+            "mutation",
+            "evaluation",
+            "population",
+        ] {
+            assert!(rows.contains(&(sample, phase)), "{sample} {phase}");
+        }
+    }
+
+    let mut json = Vec::new();
+    write_timings(&result.stat, Format::JsonLines, &mut json).unwrap();
+    let json = lines(json);
+    assert_eq!(json.len(), rows.len());
+    assert!(json[0].starts_with("{\"sample\":0,\"phase\":\""));
+}
@@ -0,0 +1,260 @@
+use gantan::termination::MaxGenerations;
+use gantan::{
+    FailurePolicy, FitnessError, GenoType, Population, SimulatorBuilder, TerminationReason,
+    TournamentSelector,
+};
+use rand::{Rng, RngCore};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Only even values can be evaluated. Mutation adds one or two.
+#[derive(Clone, Debug, PartialEq)]
+struct Even(u32);
+
+impl GenoType for Even {
+    type Fitness = u32;
+    type PhenoType = u32;
+
+    fn fitness(&self) -> Self::Fitness {
+        self.0
+    }
+    fn try_fitness(&self) -> Result<Self::Fitness, FitnessError> {
+        match self.0 % 2 {
+            0 => Ok(self.0),
+            _ => Err(FitnessError::new(format!("odd value {}", self.0))),
+        }
+    }
+    fn decode(&self) -> Self::PhenoType {
+        self.0
+    }
+    fn mutate(&mut self) {
+        self.0 += 1;
+    }
+    fn mutate_with_rng(&mut self, rng: &mut dyn RngCore) {
+        self.0 += rng.gen_range(1..=2);
+    }
+    fn crossover(_g1: &mut Self, _g2: &mut Self) {}
+}
+
+fn values(population: &Population<Even>) -> Vec<(u32, u32)> {
+    population.iter().map(|(g, f)| (g.0, *f)).collect()
+}
+
+fn builder(policy: FailurePolicy<u32>) -> SimulatorBuilder<Even, (), TournamentSelector<Even>> {
+    let genes = vec![Even(2), Even(4), Even(6), Even(8)];
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(genes))
+        .with_inspector(())
+        .with_crossover_rate(0.0)
+        .with_mutation_rate(1.0)
+        .with_selector(TournamentSelector::new(2))
+        .with_seed(11)
+        .with_failure_policy(policy)
+        .with_termination(MaxGenerations(5));
+    builder
+}
+
+#[test]
+fn abort_surfaces_the_error_in_the_result() {
+    let result = builder(FailurePolicy::Abort).build().start();
+
+    assert_eq!(result.termination, TerminationReason::Failed);
+    let error = result.error.expect("run failed");
+    assert!(error.message().starts_with("odd value"));
+    // The very first generation fails after evaluating all four children
+    // and leaves the initial population in place.
+    assert_eq!(result.generations, 0);
+    assert_eq!(result.evaluations, 4);
+    assert_eq!(values(&result.population), [(2, 2), (4, 4), (6, 6), (8, 8)]);
+}
+
+#[test]
+fn try_step_leaves_the_population_unchanged_on_error() {
+    let mut simulator = builder(FailurePolicy::Abort).build();
+    loop {
+        let before = values(simulator.population());
+        let generation = simulator.generation();
+        let evaluations = simulator.evaluations();
+        let step = simulator.try_step();
+        // Every child is mutated, so all four are evaluated, failed or not.
+        assert_eq!(simulator.evaluations(), evaluations + 4);
+        let samples = simulator.stat().samples("evaluation").map(<[_]>::len);
+        assert_eq!(samples, Some(generation + 1));
+        if let Err(error) = step {
+            assert_eq!(error.to_string(), error.message());
+            assert_eq!(values(simulator.population()), before);
+            assert_eq!(simulator.generation(), generation);
+            break;
+        }
+    }
+}
+
+#[test]
+fn abort_still_caches_children_after_the_failing_one() {
+    let keys = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&keys);
+    let mut builder = builder(FailurePolicy::Abort);
+    builder.with_fitness_cache_by(16, move |g: &Even| {
+        recorded.lock().unwrap().push(g.0);
+        g.0
+    });
+    let mut simulator = builder.build();
+    loop {
+        keys.lock().unwrap().clear();
+        if simulator.try_step().is_err() {
+            break;
+        }
+    }
+
+    // Every child is looked up first, then the even ones are inserted.
+    let keys = keys.lock().unwrap();
+    let (lookups, inserts) = keys.split_at(4);
+    let even: Vec<u32> = lookups.iter().copied().filter(|v| v % 2 == 0).collect();
+    assert_eq!(inserts, even);
+    let first_odd = lookups.iter().position(|v| v % 2 == 1).unwrap();
+    assert!(
+        lookups[first_odd..].iter().any(|v| v % 2 == 0),
+        "{lookups:?}"
+    );
+}
+
+#[test]
+#[should_panic(expected = "fitness evaluation failed: odd value")]
+fn step_panics_when_aborting() {
+    let mut simulator = builder(FailurePolicy::Abort).build();
+    for _ in 0..100 {
+        simulator.step();
+    }
+}
+
+#[test]
+#[should_panic(expected = "fitness evaluation failed: odd value")]
+fn generations_panics_when_aborting() {
+    let mut simulator = builder(FailurePolicy::Abort).build();
+    simulator.generations().take(100).for_each(drop);
+}
+
+#[test]
+fn try_generations_ends_after_the_error() {
+    let mut simulator = builder(FailurePolicy::Abort).build();
+    let steps: Vec<_> = simulator.try_generations().take(100).collect();
+
+    let (last, reports) = steps.split_last().unwrap();
+    assert!(reports.iter().all(Result::is_ok));
+    assert!(last
+        .as_ref()
+        .is_err_and(|e| e.message().starts_with("odd value")));
+    assert_eq!(simulator.generation(), reports.len());
+}
+
+#[test]
+fn worst_assigns_the_given_fitness() {
+    let mut simulator = builder(FailurePolicy::Worst(0)).build();
+    simulator.step();
+    let population = simulator.population();
+    assert!(population.iter().any(|(g, _)| g.0 % 2 == 1));
+    for (g, f) in population {
+        if g.0 % 2 == 1 {
+            assert_eq!(*f, 0);
+        } else {
+            assert_eq!(*f, g.0);
+        }
+    }
+    assert_eq!(population.len(), 4);
+    assert!(population.get_best().is_some_and(|g| g.0 % 2 == 0));
+    assert_eq!(builder(FailurePolicy::Worst(0)).build().start().error, None);
+}
+
+#[test]
+fn resample_breeds_replacements_for_failed_children() {
+    let result = builder(FailurePolicy::Resample { rounds: 50 })
+        .build()
+        .start();
+
+    assert_eq!(result.termination, TerminationReason::Criterion);
+    assert_eq!(result.population.len(), 4);
+    assert!(result.population.iter().all(|(g, _)| g.0 % 2 == 0));
+    // Failed evaluations are counted as well.
+    assert!(result.evaluations > 5 * 4);
+}
+
+#[test]
+fn resample_aborts_when_children_are_still_missing() {
+    let resample = || {
+        let mut builder = builder(FailurePolicy::Resample { rounds: 0 });
+        builder.with_elitism(1);
+        builder.build()
+    };
+    let mut simulator = resample();
+    let before = values(simulator.population());
+
+    let error = simulator.try_step().expect_err("odd children remain");
+    assert!(error.message().starts_with("odd value"));
+    assert_eq!(values(simulator.population()), before);
+
+    let result = resample().start();
+    assert_eq!(result.termination, TerminationReason::Failed);
+    assert_eq!(result.generations, 0);
+    assert_eq!(result.population.len(), 4);
+}
+
+/// Fails every other evaluation. Each copy counts its own calls.
+#[derive(Debug)]
+struct Flaky(u32, AtomicUsize);
+
+impl Clone for Flaky {
+    fn clone(&self) -> Self {
+        Flaky(self.0, AtomicUsize::new(0))
+    }
+}
+
+impl GenoType for Flaky {
+    type Fitness = u32;
+    type PhenoType = u32;
+
+    fn fitness(&self) -> Self::Fitness {
+        self.0
+    }
+    fn try_fitness(&self) -> Result<Self::Fitness, FitnessError> {
+        match self.1.fetch_add(1, Ordering::Relaxed) % 2 {
+            0 => Err(FitnessError::new("simulator crashed")),
+            _ => Ok(self.0),
+        }
+    }
+    fn decode(&self) -> Self::PhenoType {
+        self.0
+    }
+    fn mutate(&mut self) {
+        self.0 += 1;
+    }
+    fn crossover(_g1: &mut Self, _g2: &mut Self) {}
+}
+
+fn run_flaky(policy: FailurePolicy<u32>) -> (TerminationReason, usize, usize) {
+    let genes: Vec<_> = (1..=4).map(|v| Flaky(v, AtomicUsize::new(0))).collect();
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(genes))
+        .with_inspector(())
+        .with_crossover_rate(0.0)
+        .with_mutation_rate(1.0)
+        .with_selector(TournamentSelector::new(2))
+        .with_seed(3)
+        .with_failure_policy(policy)
+        .with_termination(MaxGenerations(3));
+    let result = builder.build().start();
+    (result.termination, result.generations, result.evaluations)
+}
+
+#[test]
+fn retry_evaluates_again_before_giving_up() {
+    assert_eq!(
+        run_flaky(FailurePolicy::Retry { attempts: 1 }),
+        (TerminationReason::Criterion, 3, 3 * 4 * 2)
+    );
+    assert_eq!(
+        run_flaky(FailurePolicy::Retry { attempts: 0 }),
+        (TerminationReason::Failed, 0, 4)
+    );
+}
@@ -0,0 +1,167 @@
+use gantan::termination::MaxGenerations;
+use gantan::{
+    FitnessProportionate, Float, GenoType, Objective, Population, RankSelector, Roulette,
+    SimulatorBuilder, TournamentSelector,
+};
+use rand::{Rng, RngCore};
+use std::collections::HashSet;
+
+#[test]
+fn orders_numbers_and_nan() {
+    let mut values: Vec<Float<f64>> = [2.5, f64::NAN, -1.0, f64::INFINITY, 0.0]
+        .into_iter()
+        .map(Float)
+        .collect();
+    values.sort();
+    assert!(values[0].0.is_nan());
+    let rest: Vec<f64> = values[1..].iter().map(|v| v.0).collect();
+    assert_eq!(rest, [-1.0, 0.0, 2.5, f64::INFINITY]);
+
+    assert_eq!(Float(f64::NAN), Float(f64::NAN));
+    assert_eq!(Float(-0.0f32), Float(0.0f32));
+    assert!(Float(f32::NEG_INFINITY) > Float(f32::NAN));
+}
+
+#[test]
+fn hash_agrees_with_eq() {
+    let set: HashSet<Float<f64>> = [0.0, -0.0, f64::NAN, -f64::NAN, 1.0]
+        .into_iter()
+        .map(Float)
+        .collect();
+    assert_eq!(set.len(), 3);
+}
+
+#[test]
+fn converts_into_f64() {
+    let x: f64 = Float(1.5f32).into();
+    assert_eq!(x, 1.5);
+    assert_eq!(f64::from(Float(2.0f64)), 2.0);
+    assert_eq!(Float::from(3.0f64).into_inner(), 3.0);
+    assert_eq!(format!("{:.2}", Float(1.0f64 / 3.0)), "0.33");
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Real(f64);
+
+impl GenoType for Real {
+    type Fitness = Float<f64>;
+    type PhenoType = f64;
+
+    fn fitness(&self) -> Self::Fitness {
+        Float(self.0)
+    }
+    fn decode(&self) -> Self::PhenoType {
+        self.0
+    }
+    fn mutate(&mut self) {
+        self.0 += 0.5;
+    }
+    fn crossover(_g1: &mut Self, _g2: &mut Self) {}
+}
+
+fn run(selector: impl Roulette<Real>) -> Option<f64> {
+    let genes = vec![Real(0.25), Real(1.5), Real(0.75), Real(1.0)];
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(genes))
+        .with_inspector(())
+        .with_crossover_rate(0.0)
+        .with_mutation_rate(1.0)
+        .with_elitism(1)
+        .with_selector(selector)
+        .with_seed(4)
+        .with_termination(MaxGenerations(4))
+        .with_history();
+    let result = builder.build().start();
+    assert_eq!(
+        result.history.unwrap().last().map(|r| r.best),
+        result.best.as_ref().map(|(_, f)| f.0)
+    );
+    result.population.get_best().map(|g| g.0)
+}
+
+#[test]
+fn float_fitness_works_with_every_selector() {
+    // The elite 1.5 plus four generations of +0.5 on the best child.
+    assert!(run(FitnessProportionate::new()).unwrap() >= 1.5);
+    assert!(run(RankSelector::new()).unwrap() >= 1.5);
+    assert_eq!(run(TournamentSelector::new(4)), Some(3.5));
+}
+
+#[test]
+fn nan_is_the_worst_under_both_objectives() {
+    let nan = Float(f64::NAN);
+    assert!(nan.is_nan());
+    assert!(!Float(f64::INFINITY).is_nan());
+    assert!(Objective::Maximize.is_better(&Float(-1e300), &nan));
+
+    let real = Population::from(vec![Real(f64::NAN), Real(-1.0)]);
+    assert_eq!(real.get_worst().map(|g| g.0.is_nan()), Some(true));
+    let cost = Population::from(vec![Cost(-1.0), Cost(1e300)]);
+    assert_eq!(cost.get_best(), Some(&Cost(1e300)));
+    assert_eq!(cost.get_worst(), Some(&Cost(-1.0)));
+}
+
+/// Distance to minimize; negative values cannot be evaluated.
+#[derive(Clone, Debug, PartialEq)]
+struct Cost(f64);
+
+impl GenoType for Cost {
+    type Fitness = Float<f64>;
+    type PhenoType = f64;
+
+    const OBJECTIVE: Objective = Objective::Minimize;
+
+    fn fitness(&self) -> Self::Fitness {
+        match self.0 {
+            x if x < 0.0 => Float(f64::NAN),
+            x => Float(x),
+        }
+    }
+    fn is_undefined(fitness: &Self::Fitness) -> bool {
+        fitness.is_nan()
+    }
+    fn decode(&self) -> Self::PhenoType {
+        self.0
+    }
+    fn mutate(&mut self) {}
+    fn mutate_with_rng(&mut self, rng: &mut dyn RngCore) {
+        self.0 += rng.gen_range(-1.0..0.5);
+    }
+    fn crossover(_g1: &mut Self, _g2: &mut Self) {}
+}
+
+fn minimize(selector: impl Roulette<Cost>) {
+    let genes = vec![Cost(-1.0), Cost(4.0), Cost(3.0), Cost(5.0), Cost(-2.0)];
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(genes))
+        .with_inspector(())
+        .with_crossover_rate(0.0)
+        .with_mutation_rate(1.0)
+        .with_elitism(1)
+        .with_selector(selector)
+        .with_seed(9)
+        .with_termination(MaxGenerations(10))
+        .with_history();
+    let result = builder.build().start();
+
+    let (best, fitness) = result.best.expect("a best individual");
+    assert!(best.0 >= 0.0);
+    assert!(!fitness.0.is_nan());
+    let (g, f) = result.population.get_best_with_fitness().unwrap();
+    assert!(g.0 >= 0.0 && !f.0.is_nan());
+    for record in result.history.unwrap().iter() {
+        assert!(record.best >= 0.0);
+        assert!(!record.mean.is_nan() && !record.std_dev.is_nan());
+    }
+    let first = Population::from(vec![Cost(1.0), Cost(-1.0)]);
+    assert_eq!(first.get_worst(), Some(&Cost(-1.0)));
+}
+
+#[test]
+fn nan_fitness_is_worst_when_minimizing() {
+    minimize(TournamentSelector::new(2));
+    minimize(FitnessProportionate::new());
+    minimize(RankSelector::new());
+}
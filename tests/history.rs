@@ -0,0 +1,78 @@
+use gantan::termination::MaxGenerations;
+use gantan::{GenoType, Population, Roulette, SimulatorBuilder};
+
+#[derive(Clone, Debug, PartialEq)]
+struct G(u32);
+
+impl GenoType for G {
+    type Fitness = u32;
+    type PhenoType = u32;
+
+    fn fitness(&self) -> Self::Fitness {
+        self.0
+    }
+    fn decode(&self) -> Self::PhenoType {
+        self.0
+    }
+    fn mutate(&mut self) {
+        self.0 += 1;
+    }
+    fn crossover(_g1: &mut Self, _g2: &mut Self) {}
+}
+
+/// Picks every individual in turn.
+#[derive(Default)]
+struct Cycle(Vec<G>, std::cell::Cell<usize>);
+
+impl Roulette<G> for Cycle {
+    fn reset(&mut self, population: &[(G, u32)]) {
+        self.0 = population.iter().map(|(g, _)| g.clone()).collect();
+        self.1.set(0);
+    }
+    fn choose(&self) -> G {
+        let i = self.1.get();
+        self.1.set(i + 1);
+        self.0[i % self.0.len()].clone()
+    }
+}
+
+#[test]
+fn records_every_generation() {
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(vec![G(0), G(2), G(4), G(10)]))
+        .with_inspector(())
+        .with_crossover_rate(0.0)
+        .with_mutation_rate(1.0)
+        .with_selector(Cycle::default())
+        .with_termination(MaxGenerations(3))
+        .with_history();
+    let result = builder.build().start();
+
+    let history = result.history.expect("history is enabled");
+    assert_eq!(history.len(), 3);
+    let evaluations: Vec<_> = history.iter().map(|r| r.evaluations).collect();
+    assert_eq!(evaluations, [4, 8, 12]);
+
+    // Every individual gains one point per generation.
+    let last = history.last().unwrap();
+    assert_eq!(last.generation, 2);
+    assert_eq!((last.best, last.worst), (13.0, 3.0));
+    assert_eq!((last.mean, last.median), (7.0, 6.0));
+    assert!((last.std_dev - 14f64.sqrt()).abs() < 1e-9);
+}
+
+#[test]
+fn disabled_by_default() {
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(vec![G(0), G(1)]))
+        .with_inspector(())
+        .with_crossover_rate(0.0)
+        .with_mutation_rate(0.0)
+        .with_selector(Cycle::default())
+        .with_termination(MaxGenerations(2));
+    let mut simulator = builder.build();
+    assert!(simulator.start().history.is_none());
+    assert!(simulator.history().is_none());
+}
@@ -0,0 +1,102 @@
+use gantan::inspection::{EarlyStopping, RecordBest};
+use gantan::{
+    GenoType, Inspector, InspectorExt, Population, Roulette, SimulatorBuilder, TerminationReason,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[derive(Clone, Debug, PartialEq)]
+struct G(u32);
+
+impl GenoType for G {
+    type Fitness = u32;
+    type PhenoType = u32;
+
+    fn fitness(&self) -> Self::Fitness {
+        self.0
+    }
+    fn decode(&self) -> Self::PhenoType {
+        self.0
+    }
+    fn mutate(&mut self) {
+        self.0 += 1;
+    }
+    fn crossover(_g1: &mut Self, _g2: &mut Self) {}
+}
+
+/// Always picks the fittest individual.
+#[derive(Default)]
+struct Best(Option<G>);
+
+impl Roulette<G> for Best {
+    fn reset(&mut self, population: &[(G, u32)]) {
+        self.0 = population
+            .iter()
+            .max_by_key(|(_, f)| *f)
+            .map(|(g, _)| g.clone());
+    }
+    fn choose(&self) -> G {
+        self.0.clone().unwrap()
+    }
+}
+
+fn simulator<I: Inspector<G>>(mutation_rate: f64, inspector: I) -> gantan::Simulator<G, I, Best> {
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(vec![G(0), G(0), G(0), G(0)]))
+        .with_inspector(inspector)
+        .with_crossover_rate(0.0)
+        .with_mutation_rate(mutation_rate)
+        .with_selector(Best::default());
+    builder.build()
+}
+
+#[test]
+fn closures_are_inspectors() {
+    let result = simulator(1.0, |generation: usize, _: &Population<G>| generation < 4).start();
+    assert_eq!(result.generations, 5);
+    assert_eq!(result.termination, TerminationReason::Inspector);
+}
+
+#[test]
+fn chain_runs_both_and_stops_on_either() {
+    let calls = Rc::new(RefCell::new(Vec::new()));
+    let log = calls.clone();
+    let recorder = move |generation: usize, _: &Population<G>| {
+        log.borrow_mut().push(generation);
+        true
+    };
+    let stop = |generation: usize, _: &Population<G>| generation < 2;
+    let result = simulator(1.0, recorder.chain(stop)).start();
+    assert_eq!(result.generations, 3);
+    assert_eq!(*calls.borrow(), vec![0, 1, 2]);
+}
+
+#[test]
+fn every_skips_generations() {
+    let calls = Rc::new(RefCell::new(Vec::new()));
+    let log = calls.clone();
+    let recorder = move |generation: usize, _: &Population<G>| {
+        log.borrow_mut().push(generation);
+        generation < 6
+    };
+    let result = simulator(1.0, recorder.every(3)).start();
+    assert_eq!(result.generations, 7);
+    assert_eq!(*calls.borrow(), vec![0, 3, 6]);
+}
+
+#[test]
+fn record_best_keeps_history() {
+    let stop = |generation: usize, _: &Population<G>| generation < 3;
+    let mut simulator = simulator(1.0, RecordBest::new().chain(stop));
+    simulator.start();
+    assert_eq!(simulator.inspector().0.history(), &[1, 2, 3, 4]);
+}
+
+#[test]
+fn early_stopping_waits_for_patience() {
+    // Without mutation the best fitness never improves after the first generation.
+    let result = simulator(0.0, EarlyStopping::new(3)).start();
+    assert_eq!(result.generations, 4);
+    assert_eq!(result.termination, TerminationReason::Inspector);
+}
@@ -1,5 +1,11 @@
-use gantan::{GenoType, Inspector, Population, Roulette, SimulatorBuilder};
-use std::cell::Cell;
+use gantan::{
+    GenoType, Inspector, Population, ReplacePolicy, ReplacementStrategy, Roulette,
+    SimulatorBuilder, TerminationReason, TournamentSelector,
+};
+use rand::prelude::*;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 #[derive(Clone, Debug, PartialEq)]
 struct TestGene(i32);
@@ -164,3 +170,440 @@ fn weighted_selector_upper_bound() {
     let g = sel.choose();
     assert_eq!(g, TestGene(2));
 }
+
+#[derive(Default)]
+struct WorstSelector {
+    worst: Option<TestGene>,
+}
+
+impl Roulette<TestGene> for WorstSelector {
+    fn reset(&mut self, population: &[(TestGene, <TestGene as GenoType>::Fitness)]) {
+        self.worst = population
+            .iter()
+            .min_by_key(|(_, f)| *f)
+            .map(|(g, _)| g.clone());
+    }
+    fn choose(&self) -> TestGene {
+        self.worst.clone().unwrap()
+    }
+}
+
+struct BestInspector {
+    expected_best: i32,
+    expected_len: usize,
+}
+
+impl Inspector<TestGene> for BestInspector {
+    fn inspect(&mut self, generation: usize, p: &Population<TestGene>) -> bool {
+        assert_eq!(p.len(), self.expected_len);
+        assert_eq!(p.get_best().cloned(), Some(TestGene(self.expected_best)));
+        generation < 5
+    }
+}
+
+#[test]
+fn simulator_elitism_keeps_best() {
+    let genes = vec![
+        TestGene(1),
+        TestGene(2),
+        TestGene(3),
+        TestGene(4),
+        TestGene(5),
+    ];
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(genes))
+        .with_inspector(BestInspector {
+            expected_best: 5,
+            expected_len: 5,
+        })
+        .with_crossover_rate(0.0)
+        .with_mutation_rate(0.0)
+        .with_elitism(2)
+        .with_selector(WorstSelector::default());
+    let mut sim = builder.build();
+    sim.start();
+}
+
+struct NeverInspector;
+
+impl Inspector<TestGene> for NeverInspector {
+    fn inspect(&mut self, _generation: usize, _p: &Population<TestGene>) -> bool {
+        panic!("inspector must not be called when stepping manually");
+    }
+}
+
+#[test]
+fn simulator_step_and_generations() {
+    let genes = vec![TestGene(1), TestGene(2), TestGene(3), TestGene(4)];
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(genes))
+        .with_inspector(NeverInspector)
+        .with_crossover_rate(0.0)
+        .with_mutation_rate(1.0)
+        .with_selector(CycleSelector::default());
+    let mut sim = builder.build();
+
+    let report = sim.step();
+    assert_eq!(report.generation, 0);
+    assert_eq!(report.population_size, 4);
+    assert_eq!(report.best_fitness, Some(5));
+    assert_eq!(sim.generation(), 1);
+
+    let reports: Vec<_> = sim
+        .generations()
+        .take_while(|r| r.best_fitness < Some(8))
+        .collect();
+    assert_eq!(reports.len(), 2);
+    assert_eq!(reports[1].generation, 2);
+    assert_eq!(sim.generation(), 4);
+    assert_eq!(sim.population().get_best().cloned(), Some(TestGene(8)));
+}
+
+struct CountInspector(usize);
+
+impl Inspector<TestGene> for CountInspector {
+    fn inspect(&mut self, generation: usize, _p: &Population<TestGene>) -> bool {
+        generation + 1 < self.0
+    }
+}
+
+#[test]
+fn simulator_start_returns_result() {
+    let genes = vec![TestGene(4), TestGene(3), TestGene(2), TestGene(1)];
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(genes))
+        .with_inspector(CountInspector(3))
+        .with_crossover_rate(0.0)
+        .with_mutation_rate(0.0)
+        .with_selector(WorstSelector::default());
+    let mut sim = builder.build();
+    let result = sim.start();
+
+    assert_eq!(result.generations, 3);
+    assert_eq!(result.termination, TerminationReason::Inspector);
+    assert_eq!(result.evaluations, 12);
+    assert_eq!(result.best, Some((TestGene(4), 4)));
+    assert_eq!(result.population.get_best().cloned(), Some(TestGene(1)));
+    assert_eq!(result.stat.samples("selection").map(<[u128]>::len), Some(3));
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct RandGene(u32);
+
+impl GenoType for RandGene {
+    type Fitness = u32;
+    type PhenoType = u32;
+
+    fn fitness(&self) -> Self::Fitness {
+        self.0
+    }
+    fn decode(&self) -> Self::PhenoType {
+        self.0
+    }
+    fn mutate(&mut self) {
+        unreachable!("the simulator calls mutate_with_rng");
+    }
+    fn crossover(_g1: &mut Self, _g2: &mut Self) {
+        unreachable!("the simulator calls crossover_with_rng");
+    }
+    fn mutate_with_rng(&mut self, rng: &mut dyn RngCore) {
+        self.0 = rng.gen_range(0..1000);
+    }
+    fn crossover_with_rng(g1: &mut Self, g2: &mut Self, rng: &mut dyn RngCore) {
+        if rng.gen() {
+            std::mem::swap(&mut g1.0, &mut g2.0);
+        }
+    }
+}
+
+struct RandInspector;
+
+impl Inspector<RandGene> for RandInspector {
+    fn inspect(&mut self, generation: usize, _p: &Population<RandGene>) -> bool {
+        generation < 20
+    }
+}
+
+fn seeded_run(seed: u64) -> Option<(RandGene, u32)> {
+    let genes = (0..10).map(RandGene).collect::<Vec<_>>();
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(genes))
+        .with_inspector(RandInspector)
+        .with_crossover_rate(0.5)
+        .with_mutation_rate(0.5)
+        .with_selector(TournamentSelector::with_seed(2, seed))
+        .with_seed(seed);
+    builder.build().start().best
+}
+
+#[test]
+fn simulator_seed_determines_run() {
+    assert_eq!(seeded_run(7), seeded_run(7));
+}
+
+struct SizeInspector {
+    expected_len: usize,
+    generations: usize,
+}
+
+impl Inspector<TestGene> for SizeInspector {
+    fn inspect(&mut self, generation: usize, p: &Population<TestGene>) -> bool {
+        assert_eq!(p.len(), self.expected_len);
+        generation + 1 < self.generations
+    }
+}
+
+#[test]
+fn simulator_keeps_odd_population_size() {
+    let genes = vec![
+        TestGene(1),
+        TestGene(2),
+        TestGene(3),
+        TestGene(4),
+        TestGene(5),
+    ];
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(genes))
+        .with_inspector(SizeInspector {
+            expected_len: 5,
+            generations: 10,
+        })
+        .with_crossover_rate(0.5)
+        .with_mutation_rate(0.5)
+        .with_selector(CycleSelector::default());
+    let result = builder.build().start();
+    assert_eq!(result.evaluations, 50);
+}
+
+#[test]
+fn simulator_breeds_explicit_offspring_count() {
+    let genes = vec![TestGene(1), TestGene(2), TestGene(3), TestGene(4)];
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(genes))
+        .with_inspector(SizeInspector {
+            expected_len: 8,
+            generations: 3,
+        })
+        .with_crossover_rate(0.5)
+        .with_mutation_rate(0.5)
+        .with_elitism(1)
+        .with_offspring(7)
+        .with_selector(CycleSelector::default());
+    let result = builder.build().start();
+    assert_eq!(result.evaluations, 21);
+}
+
+#[test]
+fn population_merge_and_truncate() {
+    let mut p = Population::from(vec![TestGene(3), TestGene(1)]);
+    p.merge(Population::from(vec![TestGene(4), TestGene(2)]));
+    assert_eq!(p.len(), 4);
+    p.truncate(2);
+    assert_eq!(p.len(), 2);
+    assert_eq!(p.get_best().cloned(), Some(TestGene(4)));
+}
+
+#[test]
+fn simulator_plus_replacement_keeps_parents() {
+    let genes = vec![TestGene(1), TestGene(2), TestGene(3), TestGene(4)];
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(genes))
+        .with_inspector(BestInspector {
+            expected_best: 4,
+            expected_len: 4,
+        })
+        .with_crossover_rate(0.0)
+        .with_mutation_rate(0.0)
+        .with_replacement(ReplacementStrategy::Plus)
+        .with_selector(WorstSelector::default());
+    let result = builder.build().start();
+    assert_eq!(result.evaluations, 24);
+}
+
+#[test]
+fn simulator_comma_replacement_keeps_best_offspring() {
+    let genes = vec![TestGene(1), TestGene(2), TestGene(3), TestGene(4)];
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(genes))
+        .with_inspector(CountInspector(1))
+        .with_crossover_rate(0.0)
+        .with_mutation_rate(1.0)
+        .with_offspring(8)
+        .with_replacement(ReplacementStrategy::Comma)
+        .with_selector(CycleSelector::default());
+    let result = builder.build().start();
+    assert_eq!(result.population.len(), 4);
+    assert_eq!(result.population.get_best().cloned(), Some(TestGene(5)));
+    assert_eq!(result.evaluations, 8);
+}
+
+/// Chooses individuals in order by index and records every population it is
+/// reset with.
+#[derive(Default)]
+struct IndexSelector {
+    inner: Vec<TestGene>,
+    next: Cell<usize>,
+    seen: Rc<RefCell<Vec<Vec<i32>>>>,
+}
+
+impl Roulette<TestGene> for IndexSelector {
+    fn reset(&mut self, population: &[(TestGene, <TestGene as GenoType>::Fitness)]) {
+        self.inner = population.iter().map(|(g, _)| g.clone()).collect();
+        self.seen
+            .borrow_mut()
+            .push(self.inner.iter().map(|g| g.0).collect());
+        self.next.set(0);
+    }
+    fn choose(&self) -> TestGene {
+        let i = self.choose_index(&mut thread_rng()).unwrap();
+        self.inner[i].clone()
+    }
+    fn choose_index(&self, _rng: &mut dyn RngCore) -> Option<usize> {
+        let i = self.next.get();
+        self.next.set((i + 1) % self.inner.len());
+        Some(i)
+    }
+}
+
+fn steady_state_run(policy: ReplacePolicy, mutation_rate: f64) -> Vec<Vec<i32>> {
+    let selector = IndexSelector::default();
+    let seen = selector.seen.clone();
+    let genes = vec![TestGene(1), TestGene(2), TestGene(3), TestGene(4)];
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(genes))
+        .with_inspector(CountInspector(2))
+        .with_crossover_rate(0.0)
+        .with_mutation_rate(mutation_rate)
+        .with_replacement(ReplacementStrategy::SteadyState { births: 2, policy })
+        .with_selector(selector);
+    let mut sim = builder.build();
+    let result = sim.start();
+    // Children that were not mutated keep their parent's fitness.
+    let evaluations = if mutation_rate > 0.0 { 4 } else { 0 };
+    assert_eq!(result.evaluations, evaluations);
+    sim.step();
+    let seen = seen.borrow().clone();
+    seen
+}
+
+#[test]
+fn simulator_steady_state_policies() {
+    // Each step breeds children of the first two individuals.
+    let seen = steady_state_run(ReplacePolicy::Worst, 1.0);
+    assert_eq!(seen[1], vec![3, 2, 3, 4]);
+    assert_eq!(seen[2], vec![3, 4, 3, 4]);
+
+    let seen = steady_state_run(ReplacePolicy::Oldest, 1.0);
+    assert_eq!(seen[1], vec![2, 3, 3, 4]);
+    assert_eq!(seen[2], vec![2, 3, 3, 4]);
+
+    let seen = steady_state_run(ReplacePolicy::ParentIfBetter, 1.0);
+    assert_eq!(seen[1], vec![2, 3, 3, 4]);
+    assert_eq!(seen[2], vec![3, 4, 3, 4]);
+
+    let seen = steady_state_run(ReplacePolicy::ParentIfBetter, 0.0);
+    assert_eq!(seen[2], vec![1, 2, 3, 4]);
+}
+
+/// Breeds from a fixed genome, so it works on an empty population.
+struct SpawnSelector;
+
+impl Roulette<TestGene> for SpawnSelector {
+    fn reset(&mut self, _population: &[(TestGene, <TestGene as GenoType>::Fitness)]) {}
+    fn choose(&self) -> TestGene {
+        TestGene(7)
+    }
+}
+
+#[test]
+fn simulator_steady_state_skips_replacement_in_empty_population() {
+    for policy in [
+        ReplacePolicy::Worst,
+        ReplacePolicy::Random,
+        ReplacePolicy::Oldest,
+        ReplacePolicy::ParentIfBetter,
+    ] {
+        let mut builder = SimulatorBuilder::new();
+        builder
+            .with_population(Population::from(Vec::<TestGene>::new()))
+            .with_inspector(CountInspector(2))
+            .with_crossover_rate(0.0)
+            .with_mutation_rate(1.0)
+            .with_replacement(ReplacementStrategy::SteadyState { births: 2, policy })
+            .with_selector(SpawnSelector)
+            .with_seed(4);
+        let mut sim = builder.build();
+        sim.step();
+        assert!(sim.population().is_empty(), "{policy:?}");
+    }
+}
+
+static COUNTED_EVALUATIONS: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Clone, Debug, PartialEq)]
+struct CountedGene(i32);
+
+impl GenoType for CountedGene {
+    type Fitness = i32;
+    type PhenoType = i32;
+
+    fn fitness(&self) -> Self::Fitness {
+        COUNTED_EVALUATIONS.fetch_add(1, Ordering::SeqCst);
+        self.0
+    }
+    fn decode(&self) -> Self::PhenoType {
+        self.0
+    }
+    fn mutate(&mut self) {
+        self.0 += 1;
+    }
+    fn crossover(_g1: &mut Self, _g2: &mut Self) {}
+}
+
+#[test]
+fn simulator_evaluates_each_new_individual_once() {
+    let genes: Vec<CountedGene> = (0..8).map(CountedGene).collect();
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(genes))
+        .with_inspector(|generation: usize, _: &Population<CountedGene>| generation < 2)
+        .with_crossover_rate(0.5)
+        .with_mutation_rate(0.5)
+        .with_elitism(2)
+        .with_selector(TournamentSelector::new(3))
+        .with_seed(1);
+    let result = builder.build().start();
+    // The initial population plus the modified children, never the parents,
+    // elites or children left untouched by crossover and mutation.
+    assert_eq!(result.evaluations, 14);
+    assert_eq!(
+        COUNTED_EVALUATIONS.load(Ordering::SeqCst),
+        8 + result.evaluations
+    );
+}
+
+#[test]
+fn simulator_carries_fitness_of_unchanged_children() {
+    let genes = vec![TestGene(5), TestGene(1), TestGene(3)];
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(genes))
+        .with_inspector(CountInspector(3))
+        .with_crossover_rate(0.0)
+        .with_mutation_rate(0.0)
+        .with_selector(TournamentSelector::new(2));
+    let result = builder.build().start();
+    assert_eq!(result.evaluations, 0);
+    for (g, f) in result.population.iter() {
+        assert_eq!(g.fitness(), *f);
+    }
+}
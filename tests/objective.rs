@@ -0,0 +1,144 @@
+use gantan::termination::{MaxGenerations, TargetFitness};
+use gantan::TerminationExt;
+use gantan::{
+    FitnessProportionate, GenoType, Objective, Population, RankSelector, Roulette,
+    SimulatorBuilder, TerminationReason, TournamentSelector,
+};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::cmp::Reverse;
+use std::time::Duration;
+
+/// A cost to be minimized.
+#[derive(Clone, Debug, PartialEq)]
+struct Cost(u32);
+
+impl GenoType for Cost {
+    type Fitness = u32;
+    type PhenoType = u32;
+
+    const OBJECTIVE: Objective = Objective::Minimize;
+
+    fn fitness(&self) -> Self::Fitness {
+        self.0
+    }
+    fn decode(&self) -> Self::PhenoType {
+        self.0
+    }
+    fn mutate(&mut self) {
+        self.0 = self.0.saturating_sub(1);
+    }
+    fn crossover(_g1: &mut Self, _g2: &mut Self) {}
+}
+
+fn costs(values: &[u32]) -> Population<Cost> {
+    Population::from(values.iter().copied().map(Cost).collect::<Vec<_>>())
+}
+
+#[test]
+fn objective_orders_by_preference() {
+    assert!(Objective::Maximize.is_better(&2, &1));
+    assert!(Objective::Minimize.is_better(&1, &2));
+    assert!(!Objective::Minimize.is_better(&1, &1));
+}
+
+#[test]
+fn population_prefers_smaller_fitness() {
+    let mut population = costs(&[5, 2, 9, 1, 7]);
+    assert_eq!(population.get_best(), Some(&Cost(1)));
+    assert_eq!(population.get_worst(), Some(&Cost(9)));
+    let top: Vec<u32> = population.top_k(3).iter().map(|(_, f)| **f).collect();
+    assert_eq!(top, [1, 2, 5]);
+    population.truncate(2);
+    let kept: Vec<u32> = population.iter().map(|(_, f)| *f).collect();
+    assert_eq!(kept, [1, 2]);
+}
+
+/// Counts how often each of the individuals 0, 1 and 2 is chosen.
+fn picks(selector: &mut impl Roulette<Cost>) -> [usize; 3] {
+    let population = [(Cost(1), 1), (Cost(5), 5), (Cost(10), 10)];
+    selector.reset(&population);
+    let mut rng = StdRng::seed_from_u64(3);
+    let mut counts = [0; 3];
+    for _ in 0..3000 {
+        counts[selector.choose_index(&mut rng).unwrap()] += 1;
+    }
+    counts
+}
+
+#[test]
+fn selectors_favour_smaller_fitness() {
+    // Weights are mirrored to 10, 6 and 1.
+    let counts = picks(&mut FitnessProportionate::new());
+    assert!(counts[0] > counts[1] && counts[1] > counts[2], "{counts:?}");
+    let counts = picks(&mut RankSelector::new());
+    assert!(counts[0] > counts[1] && counts[1] > counts[2], "{counts:?}");
+    let counts = picks(&mut TournamentSelector::new(2));
+    assert!(counts[0] > counts[1] && counts[1] > counts[2], "{counts:?}");
+}
+
+#[test]
+fn simulator_minimizes() {
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(costs(&[10, 12, 14, 16]))
+        .with_inspector(())
+        .with_crossover_rate(0.0)
+        .with_mutation_rate(1.0)
+        .with_elitism(1)
+        .with_selector(TournamentSelector::new(4))
+        .with_seed(9)
+        .with_termination(TargetFitness(6).or(MaxGenerations(50)))
+        .with_history();
+    let result = builder.build().start();
+
+    assert_eq!(result.termination, TerminationReason::Criterion);
+    assert_eq!(result.best.map(|(_, f)| f), Some(6));
+    let history = result.history.unwrap();
+    let last = history.last().unwrap();
+    assert_eq!(last.best, 6.0);
+    assert!(last.worst >= last.best);
+    // Elitism keeps the smallest cost, so the best never gets worse.
+    let best: Vec<f64> = history.iter().map(|r| r.best).collect();
+    assert!(best.windows(2).all(|w| w[1] <= w[0]), "{best:?}");
+}
+
+/// Fitness types only need to be `Ord + Copy`, foreign ones included.
+#[derive(Clone, Debug)]
+struct Latency(u64);
+
+impl GenoType for Latency {
+    type Fitness = Reverse<Duration>;
+    type PhenoType = u64;
+
+    fn fitness(&self) -> Self::Fitness {
+        Reverse(Duration::from_millis(self.0))
+    }
+    fn decode(&self) -> Self::PhenoType {
+        self.0
+    }
+    fn mutate(&mut self) {
+        self.0 = self.0.saturating_sub(1);
+    }
+    fn crossover(_g1: &mut Self, _g2: &mut Self) {}
+}
+
+#[test]
+fn foreign_fitness_types_work() {
+    let genes = vec![Latency(30), Latency(20), Latency(40)];
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(genes))
+        .with_inspector(())
+        .with_crossover_rate(0.0)
+        .with_mutation_rate(1.0)
+        .with_elitism(1)
+        .with_selector(TournamentSelector::new(2))
+        .with_seed(5)
+        .with_termination(MaxGenerations(3));
+    let result = builder.build().start();
+
+    let (best, _) = result.best.unwrap();
+    // The best latency drops by one per generation.
+    assert_eq!(best.0, 17);
+}
@@ -0,0 +1,121 @@
+use gantan::{GenoType, Population};
+use std::cell::Cell;
+
+thread_local! {
+    static EVALUATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct G(u32);
+
+impl GenoType for G {
+    type Fitness = u32;
+    type PhenoType = u32;
+
+    fn fitness(&self) -> Self::Fitness {
+        EVALUATIONS.with(|n| n.set(n.get() + 1));
+        self.0 % 10
+    }
+    fn decode(&self) -> Self::PhenoType {
+        self.0
+    }
+    fn mutate(&mut self) {}
+    fn crossover(_g1: &mut Self, _g2: &mut Self) {}
+}
+
+fn population() -> Population<G> {
+    Population::from(vec![G(12), G(5), G(38), G(1), G(25)])
+}
+
+#[test]
+fn iterates_with_fitness() {
+    let population = population();
+    let items: Vec<(u32, u32)> = population.iter().map(|(g, f)| (g.0, *f)).collect();
+    assert_eq!(items, [(12, 2), (5, 5), (38, 8), (1, 1), (25, 5)]);
+    assert_eq!((&population).into_iter().count(), 5);
+    assert_eq!(population.get(2), Some((&G(38), &8)));
+    assert_eq!(population.get(5), None);
+}
+
+#[test]
+fn best_and_worst() {
+    let population = population();
+    assert_eq!(population.get_best_with_fitness(), Some((&G(38), &8)));
+    assert_eq!(population.get_worst(), Some(&G(1)));
+    assert_eq!(population.get_worst_with_fitness(), Some((&G(1), &1)));
+    assert_eq!(Population::<G>::default().get_worst(), None);
+}
+
+#[test]
+fn sorted_views() {
+    let population = population();
+    let sorted: Vec<u32> = population.sorted().iter().map(|(g, _)| g.0).collect();
+    assert_eq!(sorted, [38, 5, 25, 12, 1]);
+    let top: Vec<u32> = population.top_k(2).iter().map(|(g, _)| g.0).collect();
+    assert_eq!(top, [38, 5]);
+    assert_eq!(population.top_k(10).len(), 5);
+}
+
+#[test]
+fn fitness_statistics() {
+    let population = population();
+    assert_eq!(population.mean(), Some(4.2));
+    let variance = population.variance().unwrap();
+    assert!((variance - 6.16).abs() < 1e-9);
+    assert_eq!(population.std_dev(), Some(variance.sqrt()));
+    assert_eq!(Population::<G>::default().mean(), None);
+}
+
+fn evaluations() -> usize {
+    EVALUATIONS.with(Cell::get)
+}
+
+fn genomes(population: &Population<G>) -> Vec<u32> {
+    population.iter().map(|(g, _)| g.0).collect()
+}
+
+#[test]
+fn push_and_extend_evaluate_only_new_genomes() {
+    let mut population = population();
+    let before = evaluations();
+    population.push(G(7));
+    population.extend([G(3), G(4)]);
+    assert_eq!(evaluations() - before, 3);
+    assert_eq!(genomes(&population), [12, 5, 38, 1, 25, 7, 3, 4]);
+    assert_eq!(population.get(5), Some((&G(7), &7)));
+}
+
+#[test]
+fn remove_and_replace() {
+    let mut population = population();
+    assert_eq!(population.remove(1), (G(5), 5));
+    let removed = population.remove_where(|_, f| *f < 3);
+    assert_eq!(removed, [(G(12), 2), (G(1), 1)]);
+    assert_eq!(genomes(&population), [38, 25]);
+
+    let before = evaluations();
+    assert_eq!(population.replace(0, G(9)), (G(38), 8));
+    assert_eq!(evaluations() - before, 1);
+    assert_eq!(population.get_best_with_fitness(), Some((&G(9), &9)));
+
+    population.clear();
+    assert!(population.is_empty());
+}
+
+#[test]
+fn merge_and_truncate() {
+    let mut population = population();
+    population.merge(Population::from(vec![G(9), G(0)]));
+    let before = evaluations();
+    population.truncate(3);
+    assert_eq!(evaluations(), before);
+    assert_eq!(genomes(&population), [9, 38, 5]);
+}
+
+#[test]
+fn from_evaluated_trusts_the_given_fitness() {
+    let before = evaluations();
+    let population = Population::from_evaluated(vec![(G(1), 7), (G(2), 3)]);
+    assert_eq!(evaluations(), before);
+    assert_eq!(population.get_best(), Some(&G(1)));
+}
@@ -0,0 +1,85 @@
+use gantan::{
+    GenerationReport, GenoType, Inspector, Population, Reporter, RunResult, SimulatorBuilder,
+    TournamentSelector,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+#[derive(Clone)]
+struct G(i32);
+
+impl GenoType for G {
+    type Fitness = i32;
+    type PhenoType = i32;
+
+    fn fitness(&self) -> Self::Fitness {
+        self.0
+    }
+    fn decode(&self) -> Self::PhenoType {
+        self.0
+    }
+    fn mutate(&mut self) {}
+    fn crossover(_g1: &mut Self, _g2: &mut Self) {}
+}
+
+struct Stop(usize);
+
+impl Inspector<G> for Stop {
+    fn inspect(&mut self, generation: usize, _p: &Population<G>) -> bool {
+        generation + 1 < self.0
+    }
+}
+
+#[derive(Clone, Default)]
+struct EventLog(Rc<RefCell<Vec<String>>>);
+
+impl Reporter<G> for EventLog {
+    fn on_start(&mut self, population: &Population<G>) {
+        self.0
+            .borrow_mut()
+            .push(format!("start {}", population.len()));
+    }
+    fn on_phase(&mut self, phase: &str, _elapsed: Duration) {
+        self.0.borrow_mut().push(phase.to_string());
+    }
+    fn on_generation(&mut self, report: &GenerationReport<i32>) {
+        self.0
+            .borrow_mut()
+            .push(format!("generation {}", report.generation));
+    }
+    fn on_finish(&mut self, result: &RunResult<G>) {
+        self.0
+            .borrow_mut()
+            .push(format!("finish {}", result.generations));
+    }
+}
+
+#[test]
+fn reporter_receives_events_in_order() {
+    let log = EventLog::default();
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(vec![G(1), G(2)]))
+        .with_inspector(Stop(2))
+        .with_crossover_rate(0.0)
+        .with_mutation_rate(0.0)
+        .with_selector(TournamentSelector::with_seed(2, 1))
+        .with_reporter(log.clone());
+    builder.build().start();
+
+    let phases = [
+        "selection",
+        "crossover",
+        "mutation",
+        "evaluation",
+        "population",
+    ];
+    let mut expected = vec!["start 2".to_string()];
+    for generation in 0..2 {
+        expected.extend(phases.iter().map(|p| p.to_string()));
+        expected.push(format!("generation {}", generation));
+    }
+    expected.push("finish 2".to_string());
+    assert_eq!(*log.0.borrow(), expected);
+}
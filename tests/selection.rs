@@ -16,7 +16,7 @@ impl GenoType for FG {
 
 #[test]
 fn fitness_proportionate_returns_member() {
-    let genes = vec![FG(1), FG(2), FG(3)];
+    let genes = [FG(1), FG(2), FG(3)];
     let mut sel = FitnessProportionate::with_seed(42);
     sel.reset(&[
         (genes[0].clone(), genes[0].fitness()),
@@ -31,7 +31,7 @@ fn fitness_proportionate_returns_member() {
 
 #[test]
 fn tournament_selector_returns_member() {
-    let genes = vec![FG(1), FG(2), FG(3)];
+    let genes = [FG(1), FG(2), FG(3)];
     let mut sel = TournamentSelector::with_seed(2, 123);
     sel.reset(&[
         (genes[0].clone(), genes[0].fitness()),
@@ -44,9 +44,19 @@ fn tournament_selector_returns_member() {
     }
 }
 
+#[test]
+fn tournament_selector_uses_fitness_from_reset() {
+    // The fitness handed to reset disagrees with FG::fitness on purpose.
+    let mut sel = TournamentSelector::with_seed(50, 7);
+    sel.reset(&[(FG(1), 100), (FG(2), 0), (FG(3), 0)]);
+    for _ in 0..10 {
+        assert_eq!(sel.choose().0, 1);
+    }
+}
+
 #[test]
 fn rank_selector_returns_member() {
-    let genes = vec![FG(1), FG(2), FG(3)];
+    let genes = [FG(1), FG(2), FG(3)];
     let mut sel = RankSelector::with_seed(99);
     sel.reset(&[
         (genes[0].clone(), genes[0].fitness()),
@@ -61,7 +71,7 @@ fn rank_selector_returns_member() {
 
 #[test]
 fn rank_selector_bias() {
-    let genes = vec![FG(1), FG(10)];
+    let genes = [FG(1), FG(10)];
     let mut sel = RankSelector::with_seed(7);
     sel.reset(&[
         (genes[0].clone(), genes[0].fitness()),
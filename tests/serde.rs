@@ -0,0 +1,90 @@
+#![cfg(feature = "serde")]
+
+use gantan::termination::{MaxGenerations, Stagnation};
+use gantan::{
+    GenoType, Population, RankSelector, RunResult, SimulatorBuilder, TerminationReason,
+    TournamentSelector,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct G(u32);
+
+impl GenoType for G {
+    type Fitness = u32;
+    type PhenoType = u32;
+
+    fn fitness(&self) -> Self::Fitness {
+        self.0
+    }
+    fn decode(&self) -> Self::PhenoType {
+        self.0
+    }
+    fn mutate(&mut self) {
+        self.0 += 1;
+    }
+    fn crossover(_g1: &mut Self, _g2: &mut Self) {}
+}
+
+#[test]
+fn population_keeps_stored_fitness() {
+    let population = Population::from(vec![G(1), G(5), G(3)]);
+    let json = serde_json::to_string(&population).unwrap();
+    assert_eq!(json, "[[1,1],[5,5],[3,3]]");
+
+    // The stored fitness is trusted, not recomputed.
+    let archived: Population<G> = serde_json::from_str("[[1,9],[5,5]]").unwrap();
+    assert_eq!(archived.len(), 2);
+    assert_eq!(archived.get_best(), Some(&G(1)));
+}
+
+#[test]
+fn selectors_serialize_their_configuration() {
+    let json = serde_json::to_string(&TournamentSelector::<G>::new(4)).unwrap();
+    assert_eq!(json, r#"{"size":4}"#);
+    let selector: TournamentSelector<G> = serde_json::from_str(&json).unwrap();
+    assert_eq!(serde_json::to_string(&selector).unwrap(), json);
+
+    let json = serde_json::to_string(&RankSelector::<G>::new()).unwrap();
+    assert_eq!(json, "null");
+    let _: RankSelector<G> = serde_json::from_str(&json).unwrap();
+}
+
+#[test]
+fn run_result_round_trips() {
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(vec![G(0), G(1), G(2), G(3)]))
+        .with_inspector(())
+        .with_crossover_rate(0.0)
+        .with_mutation_rate(1.0)
+        .with_selector(TournamentSelector::new(2))
+        .with_seed(5)
+        .with_termination(MaxGenerations(3))
+        .with_history();
+    let result = builder.build().start();
+
+    let json = serde_json::to_string(&result).unwrap();
+    let restored: RunResult<G> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.best, result.best);
+    assert_eq!(restored.generations, 3);
+    assert_eq!(restored.termination, TerminationReason::Criterion);
+    assert_eq!(restored.evaluations, result.evaluations);
+    assert_eq!(
+        serde_json::to_string(&restored.population).unwrap(),
+        serde_json::to_string(&result.population).unwrap()
+    );
+    assert_eq!(
+        restored.history.unwrap().records(),
+        result.history.unwrap().records()
+    );
+    assert_eq!(restored.stat.report(), result.stat.report());
+}
+
+#[test]
+fn termination_criteria_round_trip() {
+    let json = serde_json::to_string(&Stagnation::<u32>::new(10)).unwrap();
+    let _: Stagnation<u32> = serde_json::from_str(&json).unwrap();
+    let limit: MaxGenerations = serde_json::from_str("100").unwrap();
+    assert_eq!(limit.0, 100);
+}
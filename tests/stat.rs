@@ -0,0 +1,96 @@
+use gantan::termination::MaxGenerations;
+use gantan::{GenoType, Population, Roulette, RunResult, SimulatorBuilder};
+use std::time::Duration;
+
+#[derive(Clone, Debug, PartialEq)]
+struct G(u32);
+
+impl GenoType for G {
+    type Fitness = u32;
+    type PhenoType = u32;
+
+    fn fitness(&self) -> Self::Fitness {
+        self.0
+    }
+    fn decode(&self) -> Self::PhenoType {
+        self.0
+    }
+    fn mutate(&mut self) {
+        self.0 += 1;
+    }
+    fn crossover(_g1: &mut Self, _g2: &mut Self) {}
+}
+
+struct First(Option<G>);
+
+impl Roulette<G> for First {
+    fn reset(&mut self, population: &[(G, u32)]) {
+        self.0 = population.first().map(|(g, _)| g.clone());
+    }
+    fn choose(&self) -> G {
+        self.0.clone().unwrap()
+    }
+}
+
+fn run(timing: bool) -> RunResult<G> {
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(vec![G(1), G(1), G(1)]))
+        .with_inspector(())
+        .with_crossover_rate(0.5)
+        .with_mutation_rate(0.5)
+        .with_selector(First(None))
+        .with_termination(MaxGenerations(20))
+        .with_history()
+        .with_timing(timing);
+    builder.build().start()
+}
+
+#[test]
+fn phases_keep_their_order() {
+    let stat = run(true).stat;
+    let phases: Vec<&str> = stat.phases().map(|(phase, _)| phase).collect();
+    assert_eq!(
+        phases,
+        [
+            "selection",
+            "crossover",
+            "mutation",
+            "evaluation",
+            "population"
+        ]
+    );
+    let report = stat.report();
+    let names: Vec<&str> = report.iter().map(|t| t.phase.as_str()).collect();
+    assert_eq!(names, phases);
+}
+
+#[test]
+fn report_summarizes_samples() {
+    let stat = run(true).stat;
+    for timing in stat.report().iter() {
+        assert_eq!(timing.samples, 20);
+        assert_eq!(Some(timing.total), stat.total(&timing.phase));
+        assert_eq!(Some(timing.mean), stat.average(&timing.phase));
+        assert!(timing.min <= timing.p50);
+        assert!(timing.p50 <= timing.p95);
+        assert!(timing.p95 <= timing.p99);
+        assert!(timing.p99 <= timing.max);
+    }
+    assert_eq!(stat.timing("missing"), None);
+    assert_eq!(
+        stat.report().phase("mutation"),
+        stat.timing("mutation").as_ref()
+    );
+}
+
+#[test]
+fn timing_can_be_disabled() {
+    let result = run(false);
+    assert_eq!(result.generations, 20);
+    assert_eq!(result.stat.phases().count(), 0);
+    assert!(result.stat.report().phases.is_empty());
+    // Nor is the time taken by each generation measured.
+    let history = result.history.unwrap();
+    assert!(history.iter().all(|r| r.elapsed == Duration::ZERO));
+}
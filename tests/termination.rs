@@ -0,0 +1,120 @@
+use gantan::termination::{
+    LowFitnessSpread, MaxEvaluations, MaxGenerations, Stagnation, TargetFitness, TimeLimit,
+};
+use gantan::{
+    GenoType, Inspector, Population, Roulette, SimulatorBuilder, Termination, TerminationExt,
+    TerminationReason,
+};
+use std::time::Duration;
+
+#[derive(Clone, Debug, PartialEq)]
+struct G(u32);
+
+impl GenoType for G {
+    type Fitness = u32;
+    type PhenoType = u32;
+
+    fn fitness(&self) -> Self::Fitness {
+        self.0
+    }
+    fn decode(&self) -> Self::PhenoType {
+        self.0
+    }
+    fn mutate(&mut self) {
+        self.0 += 1;
+    }
+    fn crossover(_g1: &mut Self, _g2: &mut Self) {}
+}
+
+/// Always picks the fittest individual.
+#[derive(Default)]
+struct Best(Option<G>);
+
+impl Roulette<G> for Best {
+    fn reset(&mut self, population: &[(G, u32)]) {
+        self.0 = population
+            .iter()
+            .max_by_key(|(_, f)| *f)
+            .map(|(g, _)| g.clone());
+    }
+    fn choose(&self) -> G {
+        self.0.clone().unwrap()
+    }
+}
+
+fn run(
+    mutation_rate: f64,
+    termination: impl Termination<G> + 'static,
+) -> (usize, TerminationReason) {
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(vec![G(0), G(0), G(0), G(0)]))
+        .with_inspector(())
+        .with_crossover_rate(0.0)
+        .with_mutation_rate(mutation_rate)
+        .with_selector(Best::default())
+        .with_termination(termination);
+    let result = builder.build().start();
+    (result.generations, result.termination)
+}
+
+#[test]
+fn max_generations_and_evaluations() {
+    assert_eq!(
+        run(1.0, MaxGenerations(7)),
+        (7, TerminationReason::Criterion)
+    );
+    assert_eq!(run(1.0, MaxEvaluations(10)).0, 3);
+    // Criteria are checked before the first generation as well.
+    assert_eq!(
+        run(1.0, MaxGenerations(0)),
+        (0, TerminationReason::Criterion)
+    );
+}
+
+#[test]
+fn target_fitness_is_reached() {
+    // The best individual gains one point per generation.
+    assert_eq!(run(1.0, TargetFitness(5)).0, 5);
+}
+
+#[test]
+fn stagnation_and_fitness_spread() {
+    // The initial population sets the baseline, three generations fail to
+    // improve it.
+    assert_eq!(run(0.0, Stagnation::new(3)).0, 3);
+    // All fitness values are equal from the start.
+    assert_eq!(run(0.0, LowFitnessSpread(0.5)).0, 0);
+    assert_eq!(run(1.0, TargetFitness(0)).0, 0);
+}
+
+#[test]
+fn criteria_combine() {
+    assert_eq!(run(1.0, MaxGenerations(3).or(TargetFitness(10))).0, 3);
+    assert_eq!(run(1.0, MaxGenerations(3).and(TargetFitness(10))).0, 10);
+    let limit = MaxGenerations(4).or(TimeLimit(Duration::from_secs(3600)));
+    assert_eq!(run(1.0, limit).0, 4);
+}
+
+struct StopAt(usize);
+
+impl Inspector<G> for StopAt {
+    fn inspect(&mut self, generation: usize, _p: &Population<G>) -> bool {
+        generation + 1 < self.0
+    }
+}
+
+#[test]
+fn inspector_still_stops_the_run() {
+    let mut builder = SimulatorBuilder::new();
+    builder
+        .with_population(Population::from(vec![G(0), G(0)]))
+        .with_inspector(StopAt(2))
+        .with_crossover_rate(0.0)
+        .with_mutation_rate(1.0)
+        .with_selector(Best::default())
+        .with_termination(MaxGenerations(5));
+    let result = builder.build().start();
+    assert_eq!(result.generations, 2);
+    assert_eq!(result.termination, TerminationReason::Inspector);
+}